@@ -1,480 +1,988 @@
-use std::collections::HashMap;
-use std::env;
-use std::ffi::OsStr;
-use std::path::Path;
-use std::process::Command;
-use std::time::Instant;
+use std::mem;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 use image::imageops::blur;
-use image::{DynamicImage, EncodableLayout, GenericImage, ImageBuffer, Rgba};
-use rusttype::{point, Font, PositionedGlyph, Scale, VMetrics};
+use image::RgbaImage;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rusttype::{point, Scale};
 use speedy2d::color::Color;
 use speedy2d::dimen::Vector2;
-use speedy2d::image::{ImageDataType, ImageHandle, ImageSmoothingMode};
 use speedy2d::shape::Rectangle;
-use speedy2d::window::{KeyScancode, VirtualKeyCode, WindowHandler, WindowHelper};
+use speedy2d::window::{
+    KeyScancode, MouseButton, VirtualKeyCode, WindowFullscreenMode, WindowHandler, WindowHelper,
+    WindowStartupInfo,
+};
 use speedy2d::{Graphics2D, Window};
 
-pub(crate) const SCREEN_WIDTH: u32 = 810;
-pub(crate) const SCREEN_HEIGHT: u32 = 1440;
-pub(crate) const FONT_RATIO: f32 = 427.0 / 1000.0;
+use self::renderer::{NullRenderer, Renderer, SpeedyRenderer};
+
+use self::atlas::{layout_line, GlyphAtlas, LineGlyph};
+use self::clock::Clock;
+use self::export::ExportFormat;
+use self::fonts::FontChain;
+use self::layout::{Layout, Viewport};
+use self::lru::{CacheStats, LruCache, MEGABYTE};
+use self::placement::WindowPlacement;
+use self::scene::Affine;
+use self::text::{Align, FontStyle, TextLayout, TextMetrics, VerticalAlign, Weight};
+use self::timeline::Timeline;
+
+mod atlas;
+pub(crate) mod camera;
+pub(crate) mod clock;
+pub(crate) mod easing;
+mod export;
+pub(crate) mod fonts;
+pub(crate) mod layout;
+mod lru;
+pub(crate) mod pacing;
+mod placement;
+pub(crate) mod renderer;
+pub(crate) mod scene;
+pub(crate) mod text;
+mod timeline;
+pub(crate) mod tween;
 
 const RUST_IMAGE_SIZE: f32 = 405.0;
 pub(crate) const TEXT_MARGIN: u32 = 30;
+/// For the small gray texts around the visualizations: the scrubber, the
+/// expected answers.
+const ANNOTATION_STYLE: FontStyle = FontStyle::new(Weight::Light);
 
-#[derive(Debug)]
-pub(crate) struct GlyphSize {
-    height: u32,
-    width: u32,
-}
+/// The lines laid out for the glyph atlas, by text, size and style.
+type GlyphsCache = LruCache<(String, u32, FontStyle), Vec<LineGlyph>>;
+
+/// Used by `--max-seconds` when not given, far more than any day takes.
+pub(crate) const DEFAULT_MAX_EXPORT_SECONDS: u32 = 10 * 60;
 
-type GlyphsCache = HashMap<(String, u32), (VMetrics, GlyphSize, Vec<PositionedGlyph<'static>>)>;
-type RawImageCache = HashMap<(String, u32, TextType), ImageBuffer<Rgba<u8>, Vec<u8>>>;
+/// Used by `--text-cache` when not given.
+pub(crate) const DEFAULT_TEXT_CACHE_BUDGET: usize = 64 * MEGABYTE;
 
 pub(crate) struct TextManager {
-    pub(crate) font: Font<'static>,
+    pub(crate) fonts: FontChain,
     pub(crate) glyphs: GlyphsCache,
-    pub(crate) images: HashMap<(String, u32, TextType), ImageHandle>,
-    pub(crate) raw_images: RawImageCache,
+    pub(crate) atlas: GlyphAtlas,
+    /// Final answers are checked against it, see `draw_answer`.
+    pub(crate) expected_answer: Option<String>,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-pub(crate) enum GlowColor {
-    White,
-    Gold,
-    Red,
+/// The color of a glowing text, and how its glow looks. Only integers, so
+/// texts can be cached by their type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Glow {
+    /// RGBA, of the text and of its glow.
+    pub(crate) color: [u8; 4],
+    /// How far the glow spreads: the standard deviation of its blur, in
+    /// pixels of the rasterized text.
+    pub(crate) radius: u32,
+    /// How opaque the glow is, in percent.
+    pub(crate) intensity: u32,
+}
+
+impl Glow {
+    pub(crate) const WHITE: Glow = Glow::from_hex_rgb(0xffffff);
+    pub(crate) const GOLD: Glow = Glow::from_hex_rgb(0xffff66);
+    pub(crate) const RED: Glow = Glow::from_hex_rgb(0xef4444);
+
+    pub(crate) const fn new(color: [u8; 4]) -> Self {
+        Glow {
+            color,
+            radius: 8,
+            intensity: 100,
+        }
+    }
+
+    pub(crate) const fn from_hex_rgb(hex: u32) -> Self {
+        Self::new([(hex >> 16) as u8, (hex >> 8) as u8, hex as u8, 255])
+    }
+
+    pub(crate) const fn with_radius(mut self, radius: u32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub(crate) const fn with_intensity(mut self, intensity: u32) -> Self {
+        self.intensity = intensity;
+        self
+    }
+
+    /// The glow around the glyphs drawn on `image`.
+    pub(crate) fn blurred(self, image: &RgbaImage) -> RgbaImage {
+        let mut glow = blur(image, self.radius as f32);
+        for pixel in glow.pixels_mut() {
+            pixel.0[3] = (pixel.0[3] as u32 * self.intensity / 100).min(255) as u8;
+        }
+
+        glow
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) enum TextType {
-    Glow(GlowColor),
+    Glow(Glow),
     Gray,
 }
 
+impl TextType {
+    /// RGBA, of the glyphs.
+    pub(crate) fn color(self) -> [u8; 4] {
+        match self {
+            TextType::Gray => [204, 204, 204, 255],
+            TextType::Glow(glow) => glow.color,
+        }
+    }
+}
+
 impl TextManager {
-    pub(crate) fn get_glyph_info<'a>(
-        glyphs_cache: &'a mut GlyphsCache,
-        font: &Font<'static>,
-        text: String,
-        size: u32,
-    ) -> &'a mut (VMetrics, GlyphSize, Vec<PositionedGlyph<'static>>) {
-        glyphs_cache.entry((text.clone(), size)).or_insert_with(|| {
-            let metrics = font.v_metrics(Scale::uniform(size as f32));
-            let glyphs: Vec<_> = font
-                .layout(
-                    &text,
-                    Scale::uniform(size as f32),
-                    point(TEXT_MARGIN as f32, TEXT_MARGIN as f32 + metrics.ascent),
-                )
-                .collect();
-
-            let glyphs_height = (metrics.ascent - metrics.descent).ceil() as u32;
-            let glyphs_width = {
-                let min_x = glyphs
-                    .first()
-                    .map(|g| g.pixel_bounding_box().unwrap().min.x)
-                    .unwrap();
-                let max_x = glyphs
-                    .last()
-                    .map(|g| g.pixel_bounding_box().unwrap().max.x)
-                    .unwrap();
-                (max_x - min_x) as u32
-            };
+    /// The caches share `memory_budget` bytes, most of it for the glyph atlas
+    /// which draws every text. The line layouts are small.
+    pub(crate) fn new(memory_budget: usize, expected_answer: Option<String>) -> Self {
+        TextManager {
+            fonts: FontChain::new(),
+            glyphs: LruCache::new(memory_budget / 16, |glyphs| {
+                glyphs.len() * mem::size_of::<LineGlyph>()
+            }),
+            atlas: GlyphAtlas::new(memory_budget * 15 / 16),
+            expected_answer,
+        }
+    }
 
-            (
-                metrics,
-                GlyphSize {
-                    height: glyphs_height,
-                    width: glyphs_width,
-                },
-                glyphs,
-            )
-        })
+    /// Each cache with its name, to be shown.
+    pub(crate) fn cache_stats(&self) -> [(&'static str, CacheStats); 2] {
+        [
+            ("glyph atlas", self.atlas.stats()),
+            ("glyph layouts", self.glyphs.stats()),
+        ]
     }
 
-    pub(crate) fn get_raw_image<'a>(
-        raw_image_cache: &'a mut RawImageCache,
-        glyphs_cache: &mut GlyphsCache,
-        font: &Font<'static>,
-        text: String,
+    pub(crate) fn draw_text(
+        &mut self,
+        renderer: &mut dyn Renderer,
         size: u32,
         text_type: TextType,
-    ) -> &'a ImageBuffer<Rgba<u8>, Vec<u8>> {
-        let (_metrics, glyph_size, glyphs) =
-            Self::get_glyph_info(glyphs_cache, font, text.clone(), size);
-
-        raw_image_cache
-            .entry((text, size, text_type))
-            .or_insert_with(|| {
-                let mut image = DynamicImage::new_rgba8(
-                    glyph_size.width + TEXT_MARGIN * 2,
-                    glyph_size.height + TEXT_MARGIN * 2,
-                )
-                .to_rgba8();
-                // Loop through the glyphs in the text, positing each one on a line
-                for glyph in glyphs.iter() {
-                    if let Some(bounding_box) = glyph.pixel_bounding_box() {
-                        // Draw the glyph into the image per-pixel by using the draw closure
-                        glyph.draw(|x, y, v| {
-                            image.put_pixel(
-                                // Offset the position by the glyph bounding box
-                                x + bounding_box.min.x as u32,
-                                y + bounding_box.min.y as u32,
-                                // Turn the coverage into an alpha value
-                                match text_type {
-                                    TextType::Gray => Rgba([204, 204, 204, (v * 255.0) as u8]),
-                                    TextType::Glow(GlowColor::White) => {
-                                        Rgba([255, 255, 255, (v * 255.0) as u8])
-                                    }
-                                    TextType::Glow(GlowColor::Gold) => {
-                                        Rgba([255, 255, 102, (v * 255.0) as u8])
-                                    }
-                                    TextType::Glow(GlowColor::Red) => {
-                                        Rgba([239, 68, 68, (v * 255.0) as u8])
-                                    }
-                                },
-                            )
-                        });
-                    }
-                }
-
-                match text_type {
-                    TextType::Gray => image,
-                    TextType::Glow(color) => {
-                        let mut image_blur = blur(&image, 8.0);
-
-                        for glyph in glyphs {
-                            if let Some(bounding_box) = glyph.pixel_bounding_box() {
-                                // Draw the glyph into the image per-pixel by using the draw closure
-                                glyph.draw(|x, y, v| {
-                                    let pixel = image_blur.get_pixel_mut(
-                                        x + bounding_box.min.x as u32,
-                                        y + bounding_box.min.y as u32,
-                                    );
-
-                                    let a_a = pixel.0[3] as f32;
-                                    let a_b = v * 255.0;
-
-                                    let r_a = pixel.0[0] as f32;
-                                    let g_a = pixel.0[1] as f32;
-                                    let b_a = pixel.0[2] as f32;
-
-                                    let r_b = match color {
-                                        GlowColor::Red => 239.0,
-                                        _ => 255.0,
-                                    };
-                                    let g_b = match color {
-                                        GlowColor::Red => 68.0,
-                                        _ => 255.0,
-                                    };
-                                    let b_b = match color {
-                                        GlowColor::White => 255.0,
-                                        GlowColor::Gold => 102.0,
-                                        GlowColor::Red => 68.0,
-                                    };
-
-                                    let a_out = a_a + (a_b * (255.0 - a_a) / 255.0);
-                                    let r_out =
-                                        (r_a * a_a + r_b * a_b * (255.0 - a_a) / 255.0) / a_out;
-                                    let g_out =
-                                        (g_a * a_a + g_b * a_b * (255.0 - a_a) / 255.0) / a_out;
-                                    let b_out =
-                                        (b_a * a_a + b_b * a_b * (255.0 - a_a) / 255.0) / a_out;
-
-                                    pixel.0[0] = r_out as u8;
-                                    pixel.0[1] = g_out as u8;
-                                    pixel.0[2] = b_out as u8;
-                                    pixel.0[3] = a_out as u8;
-                                });
-                            }
-                        }
-
-                        image_blur
-                    }
-                }
-            })
+        position: (f32, f32),
+        text: String,
+    ) {
+        self.draw_text_align(renderer, size, text_type, position, text, Align::Center)
     }
 
-    pub(crate) fn draw_text(
+    /// Like `draw_text`, with another font of the family.
+    pub(crate) fn draw_text_style(
         &mut self,
-        graphics: &mut Graphics2D,
+        renderer: &mut dyn Renderer,
         size: u32,
         text_type: TextType,
         position: (f32, f32),
         text: String,
+        style: FontStyle,
     ) {
-        self.draw_text_align(graphics, size, text_type, position, text, Align::Center)
+        self.draw_text_layout(
+            renderer,
+            size,
+            text_type,
+            position,
+            text,
+            TextLayout::new(Align::Center, VerticalAlign::Center).with_style(style),
+        )
     }
 
     pub(crate) fn draw_text_align(
         &mut self,
-        graphics: &mut Graphics2D,
+        renderer: &mut dyn Renderer,
         size: u32,
         text_type: TextType,
         position: (f32, f32),
         text: String,
         align: Align,
     ) {
-        if text.is_empty() {
-            return;
-        }
-
-        let image = Self::get_raw_image(
-            &mut self.raw_images,
-            &mut self.glyphs,
-            &self.font,
-            text.clone(),
+        self.draw_text_layout(
+            renderer,
             size,
             text_type,
-        );
+            position,
+            text,
+            TextLayout::new(align, VerticalAlign::Center),
+        )
+    }
 
-        let image_handle = self
-            .images
-            .entry((text.clone(), size, text_type))
-            .or_insert_with(|| {
-                let image_handle = graphics
-                    .create_image_from_raw_pixels(
-                        ImageDataType::RGBA,
-                        ImageSmoothingMode::NearestNeighbor,
-                        image.dimensions(),
-                        image.as_bytes(),
-                    )
-                    .unwrap();
+    /// Draws each line of `text` under the previous one, the whole block
+    /// placed around `position` by `text_layout`.
+    pub(crate) fn draw_text_layout(
+        &mut self,
+        renderer: &mut dyn Renderer,
+        size: u32,
+        text_type: TextType,
+        position: (f32, f32),
+        text: String,
+        text_layout: TextLayout,
+    ) {
+        let metrics = self.measure_style("", size, text_layout.style);
+        let lines: Vec<&str> = text.lines().collect();
+        let line_advance = metrics.line_height() * text_layout.line_spacing;
+        let block_height =
+            metrics.ascent + metrics.descent + line_advance * lines.len().saturating_sub(1) as f32;
+
+        let first_baseline = match text_layout.vertical_align {
+            VerticalAlign::Top => position.1 + metrics.ascent,
+            VerticalAlign::Center => position.1 - block_height / 2.0 + metrics.ascent,
+            VerticalAlign::Baseline => position.1,
+            VerticalAlign::Bottom => position.1 - block_height + metrics.ascent,
+        };
+
+        // Rasterized at the size it is drawn on the canvas, then drawn at its
+        // size for the renderer.
+        let scale = renderer.text_scale();
+        let scaled_size = scaled_text_size(size, scale);
+
+        for (line_index, line) in lines.into_iter().enumerate() {
+            let ink_width = match self
+                .measure_style(line, scaled_size, text_layout.style)
+                .bounding_box
+            {
+                Some(bounding_box) => bounding_box.width(),
+                None => continue,
+            };
+            let baseline = first_baseline + line_index as f32 * line_advance;
+
+            // Placed as if drawn on a texture with a margin around the glyphs.
+            let origin = Vector2::new(
+                match text_layout.align {
+                    Align::Right => position.0 - (ink_width + TEXT_MARGIN as f32) / scale,
+                    Align::Center => position.0 - ink_width / 2.0 / scale,
+                    Align::Left => position.0 + TEXT_MARGIN as f32 / scale,
+                },
+                baseline,
+            );
+            self.draw_line(
+                renderer,
+                line,
+                scaled_size,
+                text_type,
+                text_layout.style,
+                Affine::translation(origin).then(Affine::scale(Vector2::new(1.0, 1.0) / scale)),
+                Color::WHITE,
+            );
+        }
+    }
+
+    /// Draws a single line of text from the glyph atlas, `transform` going
+    /// from the pixels of the glyphs to the renderer, the origin being the
+    /// start of the baseline.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn draw_line(
+        &mut self,
+        renderer: &mut dyn Renderer,
+        line: &str,
+        size: u32,
+        text_type: TextType,
+        style: FontStyle,
+        transform: Affine,
+        tint: Color,
+    ) {
+        let fonts = &self.fonts;
+        let glyphs = self
+            .glyphs
+            .get_or_insert_with((line.to_string(), size, style), || {
+                layout_line(fonts, line, size, style)
+            });
+        self.atlas
+            .draw_line(renderer, fonts, glyphs, size, text_type, transform, tint);
+    }
+
+    /// Measures a single line of text, in the regular font.
+    pub(crate) fn measure(&self, text: &str, size: u32) -> TextMetrics {
+        self.measure_style(text, size, FontStyle::REGULAR)
+    }
 
-                image_handle
+    pub(crate) fn measure_style(&self, text: &str, size: u32, style: FontStyle) -> TextMetrics {
+        let scale = Scale::uniform(size as f32);
+        let v_metrics = self.fonts.v_metrics(scale, style);
+        let glyphs: Vec<_> = self
+            .fonts
+            .layout(text, scale, point(0.0, 0.0), style)
+            .into_iter()
+            .map(|(_, glyph)| glyph)
+            .collect();
+
+        let advance_width = glyphs.last().map_or(0.0, |glyph| {
+            glyph.position().x + glyph.unpositioned().h_metrics().advance_width
+        });
+        let bounding_box = glyphs
+            .iter()
+            .filter_map(|glyph| glyph.pixel_bounding_box())
+            .reduce(|a, b| rusttype::Rect {
+                min: point(a.min.x.min(b.min.x), a.min.y.min(b.min.y)),
+                max: point(a.max.x.max(b.max.x), a.max.y.max(b.max.y)),
+            })
+            .map(|bounding_box| {
+                Rectangle::new(
+                    Vector2::new(bounding_box.min.x, bounding_box.min.y).into_f32(),
+                    Vector2::new(bounding_box.max.x, bounding_box.max.y).into_f32(),
+                )
             });
 
-        graphics.draw_image(
-            (
-                match align {
-                    Align::Right => position.0 - image_handle.size().x as f32,
-                    Align::Center => position.0 - image_handle.size().x as f32 / 2.0,
-                    Align::Left => position.0,
-                },
-                position.1 - image_handle.size().y as f32 / 2.0,
-            ),
-            image_handle,
-        );
+        TextMetrics {
+            advance_width,
+            ascent: v_metrics.ascent,
+            descent: -v_metrics.descent,
+            line_gap: v_metrics.line_gap,
+            bounding_box,
+        }
     }
-}
 
-pub(crate) enum Align {
-    Center,
-    Left,
-    Right,
+    /// Gold, unless the answer differs from the expected one (if any is known).
+    pub(crate) fn answer_type(&self, answer: &str) -> TextType {
+        match &self.expected_answer {
+            Some(expected) if expected != answer => TextType::Glow(Glow::RED),
+            _ => TextType::Glow(Glow::GOLD),
+        }
+    }
+
+    pub(crate) fn draw_answer(
+        &mut self,
+        renderer: &mut dyn Renderer,
+        size: u32,
+        position: (f32, f32),
+        answer: String,
+    ) {
+        self.draw_answer_align(renderer, size, position, answer, Align::Center)
+    }
+
+    /// Draws a final answer with `answer_type`, and the expected answer under
+    /// it when they differ.
+    pub(crate) fn draw_answer_align(
+        &mut self,
+        renderer: &mut dyn Renderer,
+        size: u32,
+        position: (f32, f32),
+        answer: String,
+        align: Align,
+    ) {
+        let text_type = self.answer_type(&answer);
+        let expected = match (&self.expected_answer, text_type) {
+            (Some(expected), TextType::Glow(Glow::RED)) => Some(expected.clone()),
+            _ => None,
+        };
+
+        self.draw_text_align(renderer, size, text_type, position, answer, align);
+
+        if let Some(expected) = expected {
+            self.draw_text_layout(
+                renderer,
+                size / 2,
+                TextType::Gray,
+                (position.0, position.1 + size as f32),
+                format!("expected {expected}"),
+                TextLayout::new(align, VerticalAlign::Center).with_style(ANNOTATION_STYLE),
+            );
+        }
+    }
 }
 
-pub(crate) fn ease_in_cube_ease_out_quad(x: f32) -> f32 {
-    x * x
+/// The font size of a text drawn `scale` times bigger, at least 1.
+pub(crate) fn scaled_text_size(size: u32, scale: f32) -> u32 {
+    ((size as f32 * scale).round() as u32).max(1)
 }
 
+/// Playback speeds, selected with the keys 1 to 5.
+const PLAYBACK_SPEEDS: [f32; 5] = [0.25, 0.5, 1.0, 2.0, 4.0];
+/// Time added by a single step while paused (one frame at 60 fps).
+const STEP_DURATION: Duration = Duration::from_nanos(1_000_000_000 / 60);
+/// Time skipped by the left and right arrows.
+const SEEK_DURATION: Duration = Duration::from_secs(5);
+/// How long the scrubber stays on screen after a seek (it is always shown while paused).
+const SCRUBBER_DURATION: Duration = Duration::from_secs(2);
+const SCRUBBER_MARGIN: f32 = 40.0;
+const SCRUBBER_HEIGHT: f32 = 12.0;
+
 pub(crate) struct MyWindowHandler<S: State> {
     state: S,
-    prog_name: (String, String),
+    day: u8,
+    part: u8,
+    layout: Layout,
+    /// Only used by windows, exports render the canvas as is.
+    viewport: Viewport,
+    fullscreen: bool,
     text_manager: TextManager,
-    frame: u64,
-    timings: Timings,
+    clock: Clock,
+    last_draw: Instant,
     pause: bool,
+    /// Move one frame forward while paused.
+    step: bool,
+    /// Draw the current frame again even if paused (after a step or a seek).
+    redraw: bool,
+    speed: f32,
     splashscreen: bool,
     on_start_called: bool,
+    /// Only recorded in a window, exports never go back in time.
+    timeline: Option<Timeline<S>>,
+    scrubber_visible_until: Instant,
+    mouse_position: Vector2<f32>,
+    /// Shows how the text caches do, toggled with C.
+    cache_stats_visible: bool,
 }
 
-pub(crate) struct Timings {
-    pub(crate) start: Instant,
-    pub(crate) last_frame: Instant,
-}
+impl<S: State> MyWindowHandler<S> {
+    fn new(state: S, options: &RunOptions, splashscreen: bool) -> Self {
+        MyWindowHandler {
+            pause: false,
+            step: false,
+            redraw: false,
+            speed: 1.0,
+            splashscreen,
+            clock: Clock::new(),
+            last_draw: Instant::now(),
+            day: options.day,
+            part: options.part,
+            layout: options.layout,
+            viewport: Viewport::fit(options.layout, options.layout.size()),
+            fullscreen: false,
+            state,
+            text_manager: TextManager::new(
+                options.text_cache_budget,
+                options.expected_answer.clone(),
+            ),
+            on_start_called: false,
+            timeline: None,
+            scrubber_visible_until: Instant::now(),
+            mouse_position: Vector2::ZERO,
+            cache_stats_visible: false,
+        }
+    }
 
-impl<S: State> MyWindowHandler<S> {}
+    /// Moves the animation forward before drawing a new frame. Nothing moves
+    /// before the first frame, during the splashscreen or while paused (except
+    /// for a single step).
+    pub(crate) fn tick(&mut self, delta: Duration) {
+        if !self.on_start_called || self.splashscreen {
+            return;
+        }
 
-impl<S: State> WindowHandler for MyWindowHandler<S> {
-    fn on_key_up(
-        &mut self,
-        _helper: &mut WindowHelper<()>,
-        virtual_key_code: Option<VirtualKeyCode>,
-        _scancode: KeyScancode,
-    ) {
-        if let Some(VirtualKeyCode::Space) = virtual_key_code {
-            if self.splashscreen {
-                self.splashscreen = false;
-                self.timings.start = Instant::now();
-                self.timings.last_frame = Instant::now();
-            } else {
-                self.pause = !self.pause;
-            }
+        if !self.pause {
+            self.clock.tick(delta.mul_f32(self.speed));
+        } else if self.step {
+            // Stay on the recorded frames if this part was already played.
+            let delta = self
+                .timeline
+                .as_ref()
+                .and_then(|timeline| timeline.delta_after(self.clock.frame()))
+                .unwrap_or(STEP_DURATION);
+            self.clock.tick(delta);
+            self.step = false;
+            self.redraw = true;
         }
     }
 
-    fn on_draw(&mut self, helper: &mut WindowHelper, graphics: &mut Graphics2D) {
+    /// Draws one frame, whatever the backend (window or headless).
+    pub(crate) fn draw(&mut self, renderer: &mut dyn Renderer) {
         if !self.on_start_called {
-            self.state.on_start(graphics);
+            self.state.on_start(renderer);
             self.on_start_called = true;
         }
 
-        self.frame += 1;
-
         if self.splashscreen {
-            graphics.clear_screen(Color::from_hex_rgb(0x0f0f23));
-            let rust_image = graphics
-                .create_image_from_file_path(
-                    None,
-                    ImageSmoothingMode::NearestNeighbor,
-                    "data/rust.png",
+            renderer.clear_screen(Color::from_hex_rgb(0x0f0f23));
+            let layout = self.layout;
+            // The logo goes above the title in portrait, next to it in landscape.
+            let (logo_center, title_center) = if layout.is_landscape() {
+                (
+                    Vector2::new(layout.width() / 4.0, layout.height() / 2.0 - 50.0),
+                    Vector2::new(layout.width() * 0.62, 110.0),
                 )
-                .unwrap();
-            graphics.draw_image(
+            } else {
                 (
-                    SCREEN_WIDTH as f32 / 2.0 - (RUST_IMAGE_SIZE / 2.0),
-                    SCREEN_HEIGHT as f32 / 5.0 - (RUST_IMAGE_SIZE / 2.0),
-                ),
+                    Vector2::new(layout.width() / 2.0, layout.height() / 5.0),
+                    Vector2::new(layout.width() / 2.0, layout.height() / 2.0 - 100.0),
+                )
+            };
+
+            let rust_image = renderer.create_image_from_file_path("data/rust.png");
+            renderer.draw_image(
+                logo_center - Vector2::new(RUST_IMAGE_SIZE / 2.0, RUST_IMAGE_SIZE / 2.0),
                 &rust_image,
             );
 
+            for (index, (text, size)) in
+                [("Advent", 180), ("Of", 180), ("Code", 180), ("2023", 130)]
+                    .into_iter()
+                    .enumerate()
+            {
+                self.text_manager.draw_text_style(
+                    renderer,
+                    size,
+                    TextType::Glow(Glow::WHITE),
+                    (title_center.x, title_center.y + index as f32 * 150.0),
+                    text.to_string(),
+                    FontStyle::new(Weight::Black),
+                );
+            }
             self.text_manager.draw_text(
-                graphics,
-                180,
-                TextType::Glow(GlowColor::White),
-                (
-                    SCREEN_WIDTH as f32 / 2.0,
-                    SCREEN_HEIGHT as f32 / 2.0 - 100.0 - 0.0,
-                ),
-                "Advent".to_string(),
-            );
-            self.text_manager.draw_text(
-                graphics,
-                180,
-                TextType::Glow(GlowColor::White),
-                (
-                    SCREEN_WIDTH as f32 / 2.0,
-                    SCREEN_HEIGHT as f32 / 2.0 - 100.0 + 150.0,
-                ),
-                "Of".to_string(),
-            );
-            self.text_manager.draw_text(
-                graphics,
-                180,
-                TextType::Glow(GlowColor::White),
-                (
-                    SCREEN_WIDTH as f32 / 2.0,
-                    SCREEN_HEIGHT as f32 / 2.0 - 100.0 + 300.0,
-                ),
-                "Code".to_string(),
-            );
-            self.text_manager.draw_text(
-                graphics,
-                130,
-                TextType::Glow(GlowColor::White),
-                (
-                    SCREEN_WIDTH as f32 / 2.0,
-                    SCREEN_HEIGHT as f32 / 2.0 - 100.0 + 450.0,
-                ),
-                "2023".to_string(),
-            );
-            self.text_manager.draw_text(
-                graphics,
+                renderer,
                 100,
-                TextType::Glow(GlowColor::Gold),
-                (SCREEN_WIDTH as f32 / 2.0, SCREEN_HEIGHT as f32 - 100.0),
-                format!("Day {} - Part {}", self.prog_name.0, self.prog_name.1),
+                TextType::Glow(Glow::GOLD),
+                (layout.width() / 2.0, layout.height() - 100.0),
+                format!("Day {:02} - Part {}", self.day, self.part),
             );
 
-            let hat_image = graphics
-                .create_image_from_file_path(
-                    None,
-                    ImageSmoothingMode::NearestNeighbor,
-                    "data/hat.png",
-                )
-                .unwrap();
-            graphics.draw_image((130.0, 530.0), &hat_image);
-        } else if !self.pause {
-            graphics.clear_screen(Color::from_hex_rgb(0x0f0f23));
-            let last_frame = Instant::now();
+            // On top of the A of Advent.
+            let hat_image = renderer.create_image_from_file_path("data/hat.png");
+            renderer.draw_image(title_center + Vector2::new(-275.0, -90.0), &hat_image);
+        } else if !self.pause || self.redraw {
+            if let Some(timeline) = &mut self.timeline {
+                timeline.record(&self.clock, &self.state);
+            }
+
+            renderer.clear_screen(Color::from_hex_rgb(0x0f0f23));
             self.state
-                .on_draw(&self.timings, &mut self.text_manager, graphics);
-            self.timings.last_frame = last_frame;
+                .on_draw(&self.clock, &mut self.text_manager, renderer);
+            self.redraw = false;
+
+            if self.timeline.is_some()
+                && (self.pause || Instant::now() < self.scrubber_visible_until)
+            {
+                self.draw_scrubber(renderer);
+            }
+
+            if self.cache_stats_visible {
+                self.draw_cache_stats(renderer);
+            }
         }
 
-        // if self.start.elapsed().as_secs() != 0 {
+        // if self.clock.elapsed().as_secs() != 0 {
         //     println!(
         //         "Frame n°{} - FPS {}",
-        //         self.frame,
-        //         self.frame / self.start.elapsed().as_secs()
+        //         self.clock.frame(),
+        //         self.clock.frame() / self.clock.elapsed().as_secs()
         //     );
         // }
+    }
+
+    /// Goes back (or forward) to the last frame at or before `time`.
+    fn seek(&mut self, time: Duration) {
+        let snapshot = match &self.timeline {
+            Some(timeline) => timeline.snapshot_at_time(time).cloned(),
+            None => None,
+        };
+
+        if let Some((clock, state)) = snapshot {
+            self.replay(clock, state, |clock, delta| clock.elapsed() + delta <= time);
+        }
+    }
+
+    fn seek_frame(&mut self, frame: u64) {
+        let snapshot = match &self.timeline {
+            Some(timeline) => timeline.snapshot_at_frame(frame).cloned(),
+            None => None,
+        };
+
+        if let Some((clock, state)) = snapshot {
+            self.replay(clock, state, |clock, _delta| clock.frame() < frame);
+        }
+    }
+
+    /// Restores a snapshot and plays the following frames without showing
+    /// them, as long as `keep_going(clock, next_delta)` agrees. The frame
+    /// reached is drawn on the next redraw.
+    fn replay(&mut self, clock: Clock, state: S, keep_going: impl Fn(&Clock, Duration) -> bool) {
+        let timeline = self.timeline.as_mut().unwrap();
+        self.clock = clock;
+        self.state = state;
+
+        // Textures created by the `NullRenderer` can't be drawn, don't cache them.
+        let textures = mem::take(&mut self.text_manager.atlas.textures);
+        let mut null_renderer = NullRenderer {
+            layout: self.layout,
+        };
+
+        loop {
+            let delta = timeline
+                .delta_after(self.clock.frame())
+                .unwrap_or(STEP_DURATION);
+            if !keep_going(&self.clock, delta) {
+                break;
+            }
+
+            timeline.record(&self.clock, &self.state);
+            self.state
+                .on_draw(&self.clock, &mut self.text_manager, &mut null_renderer);
+            self.clock.tick(delta);
+        }
+
+        self.text_manager.atlas.textures = textures;
+        self.redraw = true;
+        self.scrubber_visible_until = Instant::now() + SCRUBBER_DURATION;
+    }
+
+    /// The scrubber goes from the start to the furthest time played so far.
+    fn scrubber_end(&self) -> Duration {
+        let recorded = match &self.timeline {
+            Some(timeline) => timeline.end(),
+            None => Duration::ZERO,
+        };
+
+        recorded.max(self.clock.elapsed())
+    }
+
+    fn scrubber_rect(&self) -> Rectangle {
+        Rectangle::new(
+            Vector2::new(
+                SCRUBBER_MARGIN,
+                self.layout.height() - SCRUBBER_MARGIN - SCRUBBER_HEIGHT,
+            ),
+            Vector2::new(
+                self.layout.width() - SCRUBBER_MARGIN,
+                self.layout.height() - SCRUBBER_MARGIN,
+            ),
+        )
+    }
+
+    fn draw_scrubber(&mut self, renderer: &mut dyn Renderer) {
+        let end = self.scrubber_end();
+        let progress = if end.is_zero() {
+            1.0
+        } else {
+            self.clock.elapsed().as_secs_f32() / end.as_secs_f32()
+        };
+
+        let rect = self.scrubber_rect();
+        let played = Rectangle::new(
+            *rect.top_left(),
+            Vector2::new(
+                rect.top_left().x + rect.width() * progress,
+                rect.bottom_right().y,
+            ),
+        );
+
+        renderer.draw_quad(rect_to_array(&rect), Color::from_hex_rgb(0x333340));
+        renderer.draw_quad(rect_to_array(&played), Color::from_hex_rgb(0xffff66));
+
+        self.text_manager.draw_text_layout(
+            renderer,
+            30,
+            TextType::Gray,
+            (rect.top_left().x, rect.top_left().y - 30.0),
+            format!(
+                "{:.1}s / {:.1}s  #{}",
+                self.clock.elapsed().as_secs_f32(),
+                end.as_secs_f32(),
+                self.clock.frame()
+            ),
+            TextLayout::new(Align::Left, VerticalAlign::Center).with_style(ANNOTATION_STYLE),
+        );
+    }
+
+    fn draw_cache_stats(&mut self, renderer: &mut dyn Renderer) {
+        let lines: Vec<String> = self
+            .text_manager
+            .cache_stats()
+            .iter()
+            .map(|(name, stats)| format!("{name}: {stats}"))
+            .collect();
+        let background = Rectangle::new(
+            Vector2::ZERO,
+            Vector2::new(self.layout.width(), 40.0 + lines.len() as f32 * 30.0),
+        );
+
+        renderer.draw_quad(
+            rect_to_array(&background),
+            Color::from_rgba(0.0, 0.0, 0.0, 0.7),
+        );
+        self.text_manager.draw_text_layout(
+            renderer,
+            20,
+            TextType::Gray,
+            (SCRUBBER_MARGIN, 20.0),
+            lines.join("\n"),
+            TextLayout::new(Align::Left, VerticalAlign::Top).with_style(ANNOTATION_STYLE),
+        );
+    }
+}
+
+impl<S: State> WindowHandler for MyWindowHandler<S> {
+    fn on_start(&mut self, _helper: &mut WindowHelper<()>, info: WindowStartupInfo) {
+        self.viewport = Viewport::fit(self.layout, *info.viewport_size_pixels());
+    }
+
+    fn on_resize(&mut self, helper: &mut WindowHelper<()>, size_pixels: Vector2<u32>) {
+        self.viewport = Viewport::fit(self.layout, size_pixels);
+
+        // Paused frames have to be drawn again at the new size.
+        self.redraw = true;
+        helper.request_redraw();
+    }
+
+    fn on_fullscreen_status_changed(&mut self, _helper: &mut WindowHelper<()>, fullscreen: bool) {
+        self.fullscreen = fullscreen;
+    }
+
+    fn on_key_up(
+        &mut self,
+        helper: &mut WindowHelper<()>,
+        virtual_key_code: Option<VirtualKeyCode>,
+        _scancode: KeyScancode,
+    ) {
+        match virtual_key_code {
+            Some(VirtualKeyCode::Space) => {
+                if self.splashscreen {
+                    self.splashscreen = false;
+                } else {
+                    self.pause = !self.pause;
+                    // The time spent paused must not count in the next delta.
+                    self.last_draw = Instant::now();
+
+                    // Rebuild the current frame to show the scrubber over it.
+                    if self.pause {
+                        self.seek_frame(self.clock.frame());
+                    }
+                }
+            }
+            Some(VirtualKeyCode::Period) if self.pause => self.step = true,
+            Some(VirtualKeyCode::Comma) if self.pause => {
+                self.seek_frame(self.clock.frame().saturating_sub(1))
+            }
+            Some(VirtualKeyCode::Left) => {
+                self.seek(self.clock.elapsed().saturating_sub(SEEK_DURATION))
+            }
+            Some(VirtualKeyCode::Right) => self.seek(self.clock.elapsed() + SEEK_DURATION),
+            Some(VirtualKeyCode::Home) => self.seek(Duration::ZERO),
+            Some(VirtualKeyCode::Key1) => self.speed = PLAYBACK_SPEEDS[0],
+            Some(VirtualKeyCode::Key2) => self.speed = PLAYBACK_SPEEDS[1],
+            Some(VirtualKeyCode::Key3) => self.speed = PLAYBACK_SPEEDS[2],
+            Some(VirtualKeyCode::Key4) => self.speed = PLAYBACK_SPEEDS[3],
+            Some(VirtualKeyCode::Key5) => self.speed = PLAYBACK_SPEEDS[4],
+            Some(VirtualKeyCode::C) => {
+                self.cache_stats_visible = !self.cache_stats_visible;
+                self.redraw = true;
+            }
+            Some(VirtualKeyCode::F) | Some(VirtualKeyCode::F11) => {
+                helper.set_fullscreen_mode(if self.fullscreen {
+                    WindowFullscreenMode::Windowed
+                } else {
+                    WindowFullscreenMode::FullscreenBorderless
+                })
+            }
+            _ => {}
+        }
+
+        // Nothing is redrawn while paused, wake the loop up when needed.
+        if !self.pause || self.step || self.redraw {
+            helper.request_redraw();
+        }
+    }
+
+    fn on_mouse_move(&mut self, _helper: &mut WindowHelper<()>, position: Vector2<f32>) {
+        self.mouse_position = self.viewport.to_canvas(position);
+    }
 
-        // Request that we draw another frame once this one has finished
+    fn on_mouse_button_down(&mut self, helper: &mut WindowHelper<()>, button: MouseButton) {
+        let scrubber_visible = self.pause || Instant::now() < self.scrubber_visible_until;
+        if self.splashscreen || !scrubber_visible || button != MouseButton::Left {
+            return;
+        }
+
+        // Generous hit box around the thin bar.
+        let rect = self.scrubber_rect();
+        if (self.mouse_position.y - rect.top_left().y - SCRUBBER_HEIGHT / 2.0).abs()
+            > SCRUBBER_MARGIN / 2.0
+        {
+            return;
+        }
+
+        let ratio = ((self.mouse_position.x - rect.top_left().x) / rect.width()).clamp(0.0, 1.0);
+        self.seek(self.scrubber_end().mul_f32(ratio));
         helper.request_redraw();
     }
+
+    fn on_draw(&mut self, helper: &mut WindowHelper, graphics: &mut Graphics2D) {
+        let now = Instant::now();
+        self.tick(now - self.last_draw);
+        self.last_draw = now;
+
+        self.draw(&mut SpeedyRenderer::new(
+            graphics,
+            self.layout,
+            self.viewport,
+        ));
+
+        // Request that we draw another frame once this one has finished. While
+        // paused, the last frame stays on screen until a key is pressed.
+        if !self.pause || self.splashscreen {
+            helper.request_redraw();
+        }
+    }
 }
 
-pub(crate) trait State: Sized {
+/// States are cloned into the timeline, to rewind them.
+pub(crate) trait State: Sized + Clone {
     fn on_draw(
         &mut self,
-        timings: &Timings,
+        clock: &Clock,
         text_manager: &mut TextManager,
-        graphics: &mut Graphics2D,
+        renderer: &mut dyn Renderer,
     );
 
-    fn on_start(&mut self, _graphics: &mut Graphics2D) {}
+    fn on_start(&mut self, _renderer: &mut dyn Renderer) {}
+
+    /// Whether the final screen (usually the answer) is displayed. Exports stop
+    /// a few seconds after that.
+    fn is_finished(&self) -> bool {
+        false
+    }
 }
 
-pub(crate) fn run<S: State + 'static>(state: S) {
-    let prog_name = prog();
-    Command::new("bspc")
-        .args([
-            "rule",
-            "-a",
-            &format!("{}_{}", prog_name.0, prog_name.1),
-            "desktop='Term'",
-            "state=floating",
-        ])
-        .output()
-        .expect("failed to execute process");
-
-    // Load the font
-    let font_data = include_bytes!("../data/SourceCodePro-Regular.ttf");
-    // This only succeeds if collection consists of one font
-    let font = Font::try_from_bytes(font_data as &[u8]).expect("Error constructing Font");
-
-    let window = Window::new_centered("AoE", (SCREEN_WIDTH, SCREEN_HEIGHT)).unwrap();
-
-    let my_window = MyWindowHandler {
-        pause: false,
-        #[cfg(debug_assertions)]
-        splashscreen: false,
-        #[cfg(not(debug_assertions))]
-        splashscreen: true,
-        timings: Timings {
-            start: Instant::now(),
-            last_frame: Instant::now(),
-        },
-        frame: 0,
-        prog_name,
-        state,
-        text_manager: TextManager {
-            font,
-            glyphs: HashMap::new(),
-            images: HashMap::new(),
-            raw_images: HashMap::new(),
-        },
-        on_start_called: false,
-    };
+pub(crate) struct RunOptions {
+    pub(crate) day: u8,
+    pub(crate) part: u8,
+    /// Render headlessly into this file (or directory for PNG frames) instead
+    /// of opening a window.
+    pub(crate) export: Option<(ExportFormat, PathBuf)>,
+    /// Defaults to what the export format handles best.
+    pub(crate) fps: Option<u32>,
+    /// Exported frames are this many times smaller than the screen.
+    pub(crate) scale: f32,
+    /// GIF palette quantization, from 1 (best colors) to 30 (fastest).
+    pub(crate) palette_speed: i32,
+    /// Exports stop there even if the state never finishes.
+    pub(crate) max_seconds: u32,
+    /// The answer the visualization should end on, when it is known.
+    pub(crate) expected_answer: Option<String>,
+    /// Only used by windows, see `placement::from_name`.
+    pub(crate) placement: Box<dyn WindowPlacement>,
+    pub(crate) layout: Layout,
+    /// The window opens at this fraction of the canvas size, and can be
+    /// resized afterwards.
+    pub(crate) window_scale: f32,
+    /// Bytes the texts may keep cached, see `TextManager::new`.
+    pub(crate) text_cache_budget: usize,
+}
 
-    window.run_loop(my_window);
+impl RunOptions {
+    pub(crate) fn from_args(day: u8, part: u8, mut args: impl Iterator<Item = String>) -> Self {
+        let mut options = RunOptions {
+            day,
+            part,
+            export: None,
+            fps: None,
+            scale: 1.0,
+            palette_speed: 10,
+            max_seconds: DEFAULT_MAX_EXPORT_SECONDS,
+            expected_answer: None,
+            placement: placement::from_env(),
+            layout: Layout::default(),
+            window_scale: 1.0,
+            text_cache_budget: DEFAULT_TEXT_CACHE_BUDGET,
+        };
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--export" => {
+                    options.export = Some((
+                        ExportFormat::PngFrames,
+                        args.next().expect("--export <dir>").into(),
+                    ))
+                }
+                "--gif" => {
+                    options.export =
+                        Some((ExportFormat::Gif, args.next().expect("--gif <file>").into()))
+                }
+                "--apng" => {
+                    options.export = Some((
+                        ExportFormat::Apng,
+                        args.next().expect("--apng <file>").into(),
+                    ))
+                }
+                "--fps" => {
+                    options.fps = Some(
+                        args.next()
+                            .and_then(|fps| fps.parse().ok())
+                            .filter(|fps| *fps > 0)
+                            .expect("--fps <frames per second, at least 1>"),
+                    )
+                }
+                "--scale" => {
+                    options.scale = args
+                        .next()
+                        .and_then(|scale| scale.parse().ok())
+                        .filter(|scale| *scale >= 1.0)
+                        .expect("--scale <downscale factor, at least 1>")
+                }
+                "--palette-speed" => {
+                    options.palette_speed = args
+                        .next()
+                        .and_then(|speed| speed.parse().ok())
+                        .filter(|speed| (1..=30).contains(speed))
+                        .expect("--palette-speed <1 to 30>")
+                }
+                "--max-seconds" => {
+                    options.max_seconds = args
+                        .next()
+                        .and_then(|seconds| seconds.parse().ok())
+                        .filter(|seconds| *seconds > 0)
+                        .expect("--max-seconds <seconds, at least 1>")
+                }
+                "--layout" => {
+                    options.layout = args
+                        .next()
+                        .and_then(|name| Layout::from_name(&name))
+                        .expect("--layout <portrait | landscape | <width>x<height>>")
+                }
+                "--preview" => options.window_scale = 0.5,
+                "--text-cache" => {
+                    options.text_cache_budget = args
+                        .next()
+                        .and_then(|megabytes| megabytes.parse::<usize>().ok())
+                        .filter(|megabytes| *megabytes > 0)
+                        .expect("--text-cache <megabytes>")
+                        * MEGABYTE
+                }
+                "--placement" => {
+                    options.placement = placement::from_name(
+                        &args
+                            .next()
+                            .expect("--placement <none | bspc | shell command>"),
+                    )
+                }
+                _ => panic!("Unknown argument {arg}"),
+            }
+        }
+
+        options
+    }
 }
 
-fn prog() -> (String, String) {
-    let prog_name = env::args()
-        .next()
-        .as_ref()
-        .map(Path::new)
-        .and_then(Path::file_name)
-        .and_then(OsStr::to_str)
-        .map(String::from)
-        .unwrap();
+pub(crate) fn run<S: State + 'static>(state: S, options: RunOptions) {
+    if options.export.is_some() {
+        let my_window = MyWindowHandler::new(state, &options, false);
+        export::export(my_window, &options);
+        return;
+    }
+
+    options.placement.place(placement::WINDOW_CLASS);
 
-    let (day, part) = prog_name.split_once('_').unwrap();
+    let window_size = options.layout.size().into_f32() * options.window_scale;
+    let window = Window::new_centered("AoE", window_size.into_u32()).unwrap();
 
-    (day.to_string(), part.to_string())
+    let mut my_window = MyWindowHandler::new(state, &options, cfg!(not(debug_assertions)));
+    my_window.timeline = Some(Timeline::new());
+
+    window.run_loop(my_window);
+}
+
+/// Randomness used by the states, always seeded the same way so runs can be
+/// replayed exactly.
+pub(crate) fn seeded_rng() -> StdRng {
+    StdRng::seed_from_u64(2023)
+}
+
+pub(crate) fn rect_to_array(rect: &Rectangle) -> [Vector2<f32>; 4] {
+    [
+        *rect.top_left(),
+        rect.top_right(),
+        *rect.bottom_right(),
+        rect.bottom_left(),
+    ]
 }
 
 pub(crate) fn array_to_rectangle(array: [Vector2<f32>; 4]) -> Rectangle {
@@ -494,73 +1002,3 @@ pub(crate) fn rect_at_position(center: Vector2<f32>, width: f32, height: f32) ->
         Vector2::new(center.x - width / 2.0, center.y + height / 2.0),
     ]
 }
-
-pub(crate) fn rotate_rect(rect: &mut [Vector2<f32>; 4], center: Vector2<f32>, rotation_rad: f32) {
-    rect[0] = rotate_vec(rect[0], center, rotation_rad);
-    rect[1] = rotate_vec(rect[1], center, rotation_rad);
-    rect[2] = rotate_vec(rect[2], center, rotation_rad);
-    rect[3] = rotate_vec(rect[3], center, rotation_rad);
-}
-
-pub(crate) fn rotate_vec(
-    vec: Vector2<f32>,
-    center: Vector2<f32>,
-    rotation_rad: f32,
-) -> Vector2<f32> {
-    // (x·cosθ−y·sinθ ,x·sinθ+y·cosθ)
-
-    Vector2::new(
-        center.x + (vec.x - center.x) * rotation_rad.cos()
-            - (vec.y - center.y) * rotation_rad.sin(),
-        center.y
-            + (vec.x - center.x) * rotation_rad.sin()
-            + (vec.y - center.y) * rotation_rad.cos(),
-    )
-}
-
-pub(crate) fn draw_image_rotated(
-    graphics: &mut Graphics2D,
-    position: Vector2<f32>,
-    image: &ImageHandle,
-    rot_rad: f32,
-) {
-    let mut rect = rect_at_position(position, image.size().x as f32, image.size().y as f32);
-    rotate_rect(&mut rect, position, rot_rad);
-
-    let image_coords_normalized = Rectangle::new(Vector2::ZERO, Vector2::new(1.0, 1.0));
-    let color = Color::WHITE;
-
-    graphics.draw_quad_image_tinted_four_color(
-        [rect[0], rect[1], rect[2], rect[3]],
-        [color, color, color, color],
-        [
-            *image_coords_normalized.top_left(),
-            image_coords_normalized.top_right(),
-            *image_coords_normalized.bottom_right(),
-            image_coords_normalized.bottom_left(),
-        ],
-        &image,
-    );
-}
-
-pub(crate) fn translate_rect(rect: &mut [Vector2<f32>; 4], translation: Vector2<f32>) {
-    rect[0].x += translation.x;
-    rect[0].y += translation.y;
-
-    rect[1].x += translation.x;
-    rect[1].y += translation.y;
-
-    rect[2].x += translation.x;
-    rect[2].y += translation.y;
-
-    rect[3].x += translation.x;
-    rect[3].y += translation.y;
-}
-
-pub(crate) fn interp(start: f32, end: f32, percentage: f32) -> f32 {
-    if start > end {
-        start - (start - end) * percentage
-    } else {
-        start + (end - start) * percentage
-    }
-}
@@ -0,0 +1,427 @@
+use std::collections::HashMap;
+
+use image::{EncodableLayout, Rgba, RgbaImage};
+use rusttype::{point, GlyphId, Scale};
+use speedy2d::color::Color;
+use speedy2d::dimen::Vector2;
+use speedy2d::shape::Rectangle;
+
+use super::fonts::FontChain;
+use super::lru::CacheStats;
+use super::renderer::{Renderer, Texture};
+use super::scene::Affine;
+use super::text::FontStyle;
+use super::{TextType, TEXT_MARGIN};
+
+/// Width and height of the pages, bigger sprites get a page of their own.
+const PAGE_SIZE: u32 = 512;
+/// Empty pixels around each sprite, so none bleeds on its neighbours.
+const SPRITE_PADDING: u32 = 1;
+
+/// Glowing texts draw the glow of all their glyphs first, then the glyphs
+/// over it.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Layer {
+    Glow,
+    Glyph,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct SpriteKey {
+    /// In the `FontChain`, which tells the style apart.
+    font: usize,
+    glyph: GlyphId,
+    size: u32,
+    text_type: TextType,
+    layer: Layer,
+}
+
+/// A glyph of a laid out line, which only depends on the text, its size and
+/// its style.
+#[derive(Clone, Copy)]
+pub(crate) struct LineGlyph {
+    /// In the `FontChain`.
+    font: usize,
+    id: GlyphId,
+    /// From the start of the line.
+    x: f32,
+}
+
+/// The glyphs of `line` for `GlyphAtlas::draw_line`.
+pub(crate) fn layout_line(
+    fonts: &FontChain,
+    line: &str,
+    size: u32,
+    style: FontStyle,
+) -> Vec<LineGlyph> {
+    fonts
+        .layout(line, Scale::uniform(size as f32), point(0.0, 0.0), style)
+        .into_iter()
+        .map(|(font, glyph)| LineGlyph {
+            font,
+            id: glyph.id(),
+            x: glyph.position().x,
+        })
+        .collect()
+}
+
+/// A glyph (or its glow) rasterized on a page.
+#[derive(Clone, Copy)]
+struct Sprite {
+    page: usize,
+    position: Vector2<u32>,
+    size: Vector2<u32>,
+    /// From the origin of the glyph, the start of its baseline, to the top
+    /// left of the sprite.
+    offset: Vector2<f32>,
+}
+
+/// A row of sprites, as high as the highest of them.
+struct Shelf {
+    y: u32,
+    height: u32,
+    /// Where the next sprite goes.
+    x: u32,
+}
+
+struct Page {
+    image: RgbaImage,
+    shelves: Vec<Shelf>,
+    /// Changed by each sprite added: textures uploaded before are stale.
+    /// Unique among all the pages, even the evicted ones.
+    version: u64,
+    /// The last line drawn with one of its sprites.
+    last_used: u64,
+}
+
+impl Page {
+    fn new(size: Vector2<u32>, version: u64, line: u64) -> Self {
+        Page {
+            image: RgbaImage::new(size.x, size.y),
+            shelves: vec![],
+            version,
+            last_used: line,
+        }
+    }
+
+    fn memory(&self) -> usize {
+        self.image.as_raw().len()
+    }
+
+    /// Finds room for a sprite of `size`, padding included.
+    fn allocate(&mut self, size: Vector2<u32>) -> Option<Vector2<u32>> {
+        let (width, height) = self.image.dimensions();
+
+        if let Some(shelf) = self
+            .shelves
+            .iter_mut()
+            .find(|shelf| size.y <= shelf.height && shelf.x + size.x <= width)
+        {
+            shelf.x += size.x;
+            return Some(Vector2::new(shelf.x - size.x, shelf.y));
+        }
+
+        let y = self
+            .shelves
+            .last()
+            .map_or(0, |shelf| shelf.y + shelf.height);
+        if y + size.y > height || size.x > width {
+            return None;
+        }
+
+        self.shelves.push(Shelf {
+            y,
+            height: size.y,
+            x: size.x,
+        });
+        Some(Vector2::new(0, y))
+    }
+}
+
+/// Every glyph drawn by the `TextManager`, rasterized once per size and
+/// type of text (with its glow) and packed on a few pages. Texts are drawn
+/// as one quad per glyph, so new strings cost nothing once their glyphs
+/// have been seen.
+///
+/// Once the pages go over budget, the least recently used one is evicted
+/// with its sprites and its texture, and its glyphs are rasterized again
+/// when needed. The pages used by the line being drawn are never evicted.
+pub(crate) struct GlyphAtlas {
+    /// `None` once evicted, the slot is reused by the next page.
+    pages: Vec<Option<Page>>,
+    /// `None` for glyphs which draw nothing, e.g. spaces.
+    sprites: HashMap<SpriteKey, Option<Sprite>>,
+    /// The pages uploaded to the renderer, with the version they had then.
+    /// They can only be drawn by the renderer which created them.
+    pub(crate) textures: Vec<Option<(Texture, u64)>>,
+    /// Counts the lines drawn, to know which pages were used last.
+    line: u64,
+    last_version: u64,
+    /// Hits and misses are counted per sprite, evictions per page. The
+    /// textures take as much memory again, on the renderer.
+    stats: CacheStats,
+}
+
+impl GlyphAtlas {
+    pub(crate) fn new(budget: usize) -> Self {
+        GlyphAtlas {
+            pages: vec![],
+            sprites: HashMap::new(),
+            textures: vec![],
+            line: 0,
+            last_version: 0,
+            stats: CacheStats {
+                budget,
+                ..CacheStats::default()
+            },
+        }
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Draws a single line of text, laid out by `layout_line` at `size`.
+    /// `transform` goes from the pixels of the glyphs, the origin being the
+    /// start of the baseline, to the renderer.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn draw_line(
+        &mut self,
+        renderer: &mut dyn Renderer,
+        fonts: &FontChain,
+        glyphs: &[LineGlyph],
+        size: u32,
+        text_type: TextType,
+        transform: Affine,
+        tint: Color,
+    ) {
+        self.line += 1;
+        let layers: &[Layer] = match text_type {
+            TextType::Glow(_) => &[Layer::Glow, Layer::Glyph],
+            TextType::Gray => &[Layer::Glyph],
+        };
+
+        // Every sprite is added before drawing, so a page is uploaded once
+        // for the whole line.
+        let sprites: Vec<Vec<_>> = layers
+            .iter()
+            .map(|layer| {
+                glyphs
+                    .iter()
+                    .filter_map(|glyph| {
+                        let key = SpriteKey {
+                            font: glyph.font,
+                            glyph: glyph.id,
+                            size,
+                            text_type,
+                            layer: *layer,
+                        };
+
+                        self.sprite(fonts, key).map(|sprite| (sprite, glyph.x))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        for (sprite, x) in sprites.into_iter().flatten() {
+            let top_left = Vector2::new(x, 0.0) + sprite.offset;
+            let size = sprite.size.into_f32();
+            let quad = [
+                top_left,
+                top_left + Vector2::new(size.x, 0.0),
+                top_left + size,
+                top_left + Vector2::new(0.0, size.y),
+            ]
+            .map(|corner| transform.apply(corner));
+
+            let page_size = self.page(sprite.page).image.dimensions();
+            let page_size = Vector2::new(page_size.0, page_size.1).into_f32();
+            let region = Rectangle::new(
+                Vector2::new(
+                    sprite.position.x as f32 / page_size.x,
+                    sprite.position.y as f32 / page_size.y,
+                ),
+                Vector2::new(
+                    (sprite.position.x as f32 + size.x) / page_size.x,
+                    (sprite.position.y as f32 + size.y) / page_size.y,
+                ),
+            );
+
+            let texture = self.texture(renderer, sprite.page);
+            renderer.draw_quad_image_region(quad, &texture, region, tint);
+        }
+    }
+
+    fn sprite(&mut self, fonts: &FontChain, key: SpriteKey) -> Option<Sprite> {
+        if let Some(sprite) = self.sprites.get(&key).copied() {
+            self.stats.hits += 1;
+            if let Some(sprite) = sprite {
+                self.pages[sprite.page].as_mut().unwrap().last_used = self.line;
+            }
+
+            return sprite;
+        }
+
+        self.stats.misses += 1;
+        let sprite = rasterize(fonts, key).map(|(image, offset)| self.add(image, offset));
+        self.sprites.insert(key, sprite);
+
+        sprite
+    }
+
+    fn add(&mut self, image: RgbaImage, offset: Vector2<f32>) -> Sprite {
+        let size = Vector2::new(image.width(), image.height());
+        let padded = size + Vector2::new(SPRITE_PADDING, SPRITE_PADDING) * 2;
+
+        let (page, position) = match self.pages.iter_mut().enumerate().find_map(|(index, page)| {
+            let position = page.as_mut()?.allocate(padded)?;
+            Some((index, position))
+        }) {
+            Some(found) => found,
+            None => self.add_page(padded),
+        };
+
+        self.last_version += 1;
+        let line = self.line;
+        let version = self.last_version;
+        let page_content = self.pages[page].as_mut().unwrap();
+        let position = position + Vector2::new(SPRITE_PADDING, SPRITE_PADDING);
+        for (x, y, pixel) in image.enumerate_pixels() {
+            page_content
+                .image
+                .put_pixel(position.x + x, position.y + y, *pixel);
+        }
+        page_content.version = version;
+        page_content.last_used = line;
+
+        Sprite {
+            page,
+            position,
+            size,
+            offset,
+        }
+    }
+
+    /// A new page with room for a sprite of `size`, evicting others first
+    /// if it would go over budget.
+    fn add_page(&mut self, size: Vector2<u32>) -> (usize, Vector2<u32>) {
+        self.last_version += 1;
+        let mut page = Page::new(
+            Vector2::new(size.x.max(PAGE_SIZE), size.y.max(PAGE_SIZE)),
+            self.last_version,
+            self.line,
+        );
+        let position = page.allocate(size).unwrap();
+
+        while self.stats.used + page.memory() > self.stats.budget && self.evict_page() {}
+        self.stats.used += page.memory();
+
+        let index = match self.pages.iter().position(Option::is_none) {
+            Some(index) => {
+                self.pages[index] = Some(page);
+                index
+            }
+            None => {
+                self.pages.push(Some(page));
+                self.pages.len() - 1
+            }
+        };
+
+        (index, position)
+    }
+
+    /// Evicts the least recently used page, if any wasn't used by the
+    /// current line.
+    fn evict_page(&mut self) -> bool {
+        let Some(index) = self
+            .pages
+            .iter()
+            .enumerate()
+            .filter_map(|(index, page)| Some((index, page.as_ref()?.last_used)))
+            .filter(|(_, last_used)| *last_used < self.line)
+            .min_by_key(|(_, last_used)| *last_used)
+            .map(|(index, _)| index)
+        else {
+            return false;
+        };
+
+        let page = self.pages[index].take().unwrap();
+        self.stats.used -= page.memory();
+        self.stats.evictions += 1;
+        if let Some(texture) = self.textures.get_mut(index) {
+            *texture = None;
+        }
+        self.sprites
+            .retain(|_, sprite| sprite.is_none_or(|sprite| sprite.page != index));
+
+        true
+    }
+
+    fn page(&self, page: usize) -> &Page {
+        self.pages[page].as_ref().unwrap()
+    }
+
+    /// The page as uploaded to `renderer`, uploaded again if sprites were
+    /// added since.
+    fn texture(&mut self, renderer: &mut dyn Renderer, page: usize) -> Texture {
+        if self.textures.len() < self.pages.len() {
+            self.textures.resize_with(self.pages.len(), || None);
+        }
+
+        let Page { image, version, .. } = self.pages[page].as_ref().unwrap();
+        match &self.textures[page] {
+            Some((texture, uploaded)) if uploaded == version => texture.clone(),
+            _ => {
+                let texture =
+                    renderer.create_image_from_raw_pixels(image.dimensions(), image.as_bytes());
+                self.textures[page] = Some((texture.clone(), *version));
+
+                texture
+            }
+        }
+    }
+}
+
+/// The sprite of a glyph and its offset from the origin of the glyph, `None`
+/// when it draws nothing.
+fn rasterize(fonts: &FontChain, key: SpriteKey) -> Option<(RgbaImage, Vector2<f32>)> {
+    let glyph = fonts
+        .font(key.font)
+        .glyph(key.glyph)
+        .scaled(Scale::uniform(key.size as f32))
+        .positioned(point(0.0, 0.0));
+    let bounding_box = glyph.pixel_bounding_box()?;
+
+    // The glow spreads around the glyph, it fades out about three radiuses
+    // away from it.
+    let glow = match (key.layer, key.text_type) {
+        (Layer::Glow, TextType::Glow(glow)) => Some(glow),
+        _ => None,
+    };
+    let padding = glow.map_or(0, |glow| TEXT_MARGIN.max(glow.radius * 3));
+    let [r, g, b, a] = key.text_type.color();
+
+    let mut image = RgbaImage::new(
+        bounding_box.width() as u32 + padding * 2,
+        bounding_box.height() as u32 + padding * 2,
+    );
+    glyph.draw(|x, y, v| {
+        image.put_pixel(
+            x + padding,
+            y + padding,
+            Rgba([r, g, b, (v * a as f32) as u8]),
+        );
+    });
+
+    let image = match glow {
+        Some(glow) => glow.blurred(&image),
+        None => image,
+    };
+    let offset = Vector2::new(
+        bounding_box.min.x - padding as i32,
+        bounding_box.min.y - padding as i32,
+    )
+    .into_f32();
+
+    Some((image, offset))
+}
@@ -0,0 +1,213 @@
+use std::time::Duration;
+
+use speedy2d::color::Color;
+use speedy2d::dimen::Vector2;
+use speedy2d::shape::Rectangle;
+
+use super::clock::Clock;
+use super::easing::{ease_in_out_cubic, Easing};
+use super::layout::Layout;
+use super::renderer::{Renderer, Texture};
+use super::scene::Affine;
+use super::tween::Animated;
+
+/// Looks at a world larger than the canvas: the point in focus is drawn on
+/// the anchor of the canvas, and the world around it is scaled by the zoom.
+/// Both are animated with the clock, so states holding a camera can be
+/// replayed.
+#[derive(Clone, Debug)]
+pub(crate) struct Camera {
+    focus: Animated<Vector2<f32>>,
+    zoom: Animated<f32>,
+    /// Where the focus is drawn on the canvas.
+    anchor: Vector2<f32>,
+    /// How long it takes, and how, to catch up with a followed target.
+    follow_duration: Duration,
+    follow_easing: Easing,
+}
+
+impl Camera {
+    /// Draws the world as is: the center of the canvas on itself, at zoom 1.
+    pub(crate) fn new(layout: Layout) -> Self {
+        let center = Vector2::new(layout.width() / 2.0, layout.height() / 2.0);
+
+        Camera {
+            focus: Animated::new(center),
+            zoom: Animated::new(1.0),
+            anchor: center,
+            follow_duration: Duration::from_millis(500),
+            follow_easing: ease_in_out_cubic,
+        }
+    }
+
+    /// Draws the focus on `anchor` instead of the center of the canvas, so
+    /// zooming keeps it there, e.g. the top of a growing list.
+    pub(crate) fn anchored(mut self, anchor: Vector2<f32>, focus: Vector2<f32>) -> Self {
+        self.anchor = anchor;
+        self.focus.set(focus);
+        self
+    }
+
+    pub(crate) fn zoomed(mut self, zoom: f32) -> Self {
+        self.zoom.set(zoom);
+        self
+    }
+
+    /// How `follow` catches up with its target.
+    pub(crate) fn following(mut self, duration: Duration, easing: Easing) -> Self {
+        self.follow_duration = duration;
+        self.follow_easing = easing;
+        self
+    }
+
+    pub(crate) fn pan_to(
+        &mut self,
+        start: Duration,
+        focus: Vector2<f32>,
+        duration: Duration,
+        easing: Easing,
+    ) {
+        self.focus.animate(start, focus, duration, easing);
+    }
+
+    pub(crate) fn zoom_to(
+        &mut self,
+        start: Duration,
+        zoom: f32,
+        duration: Duration,
+        easing: Easing,
+    ) {
+        self.zoom.animate(start, zoom, duration, easing);
+    }
+
+    /// Pans to `target` from `start` when it moved, to be called with the
+    /// target at each frame. A target moving again before the camera caught
+    /// up is followed from wherever the camera is. `start` should only
+    /// depend on the clock, e.g. the start of a `pacing::Step`.
+    pub(crate) fn follow(&mut self, start: Duration, target: Vector2<f32>) {
+        if *self.focus.target() != target {
+            self.focus
+                .animate(start, target, self.follow_duration, self.follow_easing);
+        }
+    }
+
+    pub(crate) fn focus(&self, clock: &Clock) -> Vector2<f32> {
+        self.focus.value(clock)
+    }
+
+    pub(crate) fn zoom(&self, clock: &Clock) -> f32 {
+        self.zoom.value(clock)
+    }
+
+    /// Whether the camera stopped moving: it pans and zooms no more.
+    pub(crate) fn is_settled(&self, clock: &Clock) -> bool {
+        self.focus.is_finished(clock) && self.zoom.is_finished(clock)
+    }
+
+    /// From the world to the canvas.
+    pub(crate) fn transform(&self, clock: &Clock) -> Affine {
+        Affine::translation(self.anchor)
+            .then(Affine::scale(Vector2::new(1.0, 1.0) * self.zoom(clock)))
+            .then(Affine::translation(Vector2::ZERO - self.focus(clock)))
+    }
+
+    pub(crate) fn to_world(&self, clock: &Clock, point: Vector2<f32>) -> Vector2<f32> {
+        self.focus(clock) + (point - self.anchor) / self.zoom(clock)
+    }
+
+    /// The part of the world on the canvas, to skip drawing the rest.
+    pub(crate) fn visible_rect(&self, clock: &Clock, layout: Layout) -> Rectangle {
+        Rectangle::new(
+            self.to_world(clock, Vector2::ZERO),
+            self.to_world(clock, Vector2::new(layout.width(), layout.height())),
+        )
+    }
+
+    /// Draws the world on `renderer` as seen by the camera at this frame.
+    pub(crate) fn renderer<'a>(
+        &self,
+        clock: &Clock,
+        renderer: &'a mut dyn Renderer,
+    ) -> CameraRenderer<'a> {
+        CameraRenderer {
+            renderer,
+            transform: self.transform(clock),
+            zoom: self.zoom(clock),
+        }
+    }
+}
+
+/// A `Renderer` drawing through a `Camera`: everything drawn on it is moved
+/// and scaled from the world to the canvas.
+pub(crate) struct CameraRenderer<'a> {
+    renderer: &'a mut dyn Renderer,
+    transform: Affine,
+    zoom: f32,
+}
+
+impl Renderer for CameraRenderer<'_> {
+    fn layout(&self) -> Layout {
+        self.renderer.layout()
+    }
+
+    fn text_scale(&self) -> f32 {
+        self.renderer.text_scale() * self.zoom
+    }
+
+    fn clear_screen(&mut self, color: Color) {
+        self.renderer.clear_screen(color);
+    }
+
+    fn create_image_from_raw_pixels(&mut self, size: (u32, u32), pixels: &[u8]) -> Texture {
+        self.renderer.create_image_from_raw_pixels(size, pixels)
+    }
+
+    fn create_image_from_file_path(&mut self, path: &str) -> Texture {
+        self.renderer.create_image_from_file_path(path)
+    }
+
+    fn draw_quad(&mut self, quad: [Vector2<f32>; 4], color: Color) {
+        self.renderer
+            .draw_quad(quad.map(|point| self.transform.apply(point)), color);
+    }
+
+    fn draw_quad_image_region(
+        &mut self,
+        quad: [Vector2<f32>; 4],
+        texture: &Texture,
+        region: Rectangle,
+        tint: Color,
+    ) {
+        self.renderer.draw_quad_image_region(
+            quad.map(|point| self.transform.apply(point)),
+            texture,
+            region,
+            tint,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::easing::ease_in_quad;
+
+    #[test]
+    fn pan_to_eases_to_the_focus() {
+        let target = Vector2::new(605.0, 720.0);
+        let mut camera = Camera::new(Layout::PORTRAIT);
+        camera.pan_to(Duration::ZERO, target, Duration::from_secs(2), ease_in_quad);
+
+        let mut clock = Clock::new();
+        assert_eq!(camera.focus(&clock), Vector2::new(405.0, 720.0));
+
+        // A quarter of the way at half the time.
+        clock.tick(Duration::from_secs(1));
+        assert_eq!(camera.focus(&clock), Vector2::new(455.0, 720.0));
+        assert!(!camera.is_settled(&clock));
+
+        clock.tick(Duration::from_secs(1));
+        assert_eq!(camera.focus(&clock), target);
+        assert!(camera.is_settled(&clock));
+    }
+}
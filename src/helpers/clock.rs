@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+/// Animation time, owned by `MyWindowHandler`. States never read the wall
+/// clock: they only see the frame deltas fed to this clock, so a run can be
+/// replayed exactly (and exported frame by frame).
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Clock {
+    elapsed: Duration,
+    delta: Duration,
+    frame: u64,
+}
+
+impl Clock {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Time since the first frame of the animation (the splashscreen doesn't count).
+    pub(crate) fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Time between the previous frame and this one.
+    pub(crate) fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    /// Index of the current frame, starting at 0.
+    pub(crate) fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    pub(crate) fn tick(&mut self, delta: Duration) {
+        self.elapsed += delta;
+        self.delta = delta;
+        self.frame += 1;
+    }
+}
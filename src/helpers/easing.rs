@@ -0,0 +1,203 @@
+//! Easing functions, from <https://easings.net>. They map the progress of an
+//! animation, from 0 to 1, to how far along its value is: 0 and 1 stay in
+//! place, back and elastic easings go past them on the way.
+
+use std::f32::consts::PI;
+
+pub(crate) type Easing = fn(f32) -> f32;
+
+pub(crate) fn linear(x: f32) -> f32 {
+    x
+}
+
+pub(crate) fn ease_in_sine(x: f32) -> f32 {
+    1.0 - (x * PI / 2.0).cos()
+}
+
+pub(crate) fn ease_out_sine(x: f32) -> f32 {
+    (x * PI / 2.0).sin()
+}
+
+pub(crate) fn ease_in_out_sine(x: f32) -> f32 {
+    -((x * PI).cos() - 1.0) / 2.0
+}
+
+pub(crate) fn ease_in_quad(x: f32) -> f32 {
+    x * x
+}
+
+pub(crate) fn ease_out_quad(x: f32) -> f32 {
+    1.0 - (1.0 - x) * (1.0 - x)
+}
+
+pub(crate) fn ease_in_out_quad(x: f32) -> f32 {
+    if x < 0.5 {
+        2.0 * x * x
+    } else {
+        1.0 - (-2.0 * x + 2.0).powi(2) / 2.0
+    }
+}
+
+pub(crate) fn ease_in_cubic(x: f32) -> f32 {
+    x * x * x
+}
+
+pub(crate) fn ease_out_cubic(x: f32) -> f32 {
+    1.0 - (1.0 - x).powi(3)
+}
+
+pub(crate) fn ease_in_out_cubic(x: f32) -> f32 {
+    if x < 0.5 {
+        4.0 * x * x * x
+    } else {
+        1.0 - (-2.0 * x + 2.0).powi(3) / 2.0
+    }
+}
+
+/// How far back easings overshoot.
+const BACK: f32 = 1.70158;
+
+pub(crate) fn ease_in_back(x: f32) -> f32 {
+    (BACK + 1.0) * x * x * x - BACK * x * x
+}
+
+pub(crate) fn ease_out_back(x: f32) -> f32 {
+    1.0 + (BACK + 1.0) * (x - 1.0).powi(3) + BACK * (x - 1.0).powi(2)
+}
+
+pub(crate) fn ease_in_out_back(x: f32) -> f32 {
+    let back = BACK * 1.525;
+
+    if x < 0.5 {
+        (2.0 * x).powi(2) * ((back + 1.0) * 2.0 * x - back) / 2.0
+    } else {
+        ((2.0 * x - 2.0).powi(2) * ((back + 1.0) * (x * 2.0 - 2.0) + back) + 2.0) / 2.0
+    }
+}
+
+pub(crate) fn ease_in_elastic(x: f32) -> f32 {
+    if x <= 0.0 || x >= 1.0 {
+        return x.clamp(0.0, 1.0);
+    }
+
+    -(2.0_f32.powf(10.0 * x - 10.0)) * ((x * 10.0 - 10.75) * (2.0 * PI / 3.0)).sin()
+}
+
+pub(crate) fn ease_out_elastic(x: f32) -> f32 {
+    if x <= 0.0 || x >= 1.0 {
+        return x.clamp(0.0, 1.0);
+    }
+
+    2.0_f32.powf(-10.0 * x) * ((x * 10.0 - 0.75) * (2.0 * PI / 3.0)).sin() + 1.0
+}
+
+pub(crate) fn ease_in_out_elastic(x: f32) -> f32 {
+    if x <= 0.0 || x >= 1.0 {
+        return x.clamp(0.0, 1.0);
+    }
+
+    let wave = ((20.0 * x - 11.125) * (2.0 * PI / 4.5)).sin();
+    if x < 0.5 {
+        -(2.0_f32.powf(20.0 * x - 10.0) * wave) / 2.0
+    } else {
+        2.0_f32.powf(-20.0 * x + 10.0) * wave / 2.0 + 1.0
+    }
+}
+
+pub(crate) fn ease_in_bounce(x: f32) -> f32 {
+    1.0 - ease_out_bounce(1.0 - x)
+}
+
+pub(crate) fn ease_out_bounce(x: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+
+    if x < 1.0 / D {
+        N * x * x
+    } else if x < 2.0 / D {
+        let x = x - 1.5 / D;
+        N * x * x + 0.75
+    } else if x < 2.5 / D {
+        let x = x - 2.25 / D;
+        N * x * x + 0.9375
+    } else {
+        let x = x - 2.625 / D;
+        N * x * x + 0.984375
+    }
+}
+
+pub(crate) fn ease_in_out_bounce(x: f32) -> f32 {
+    if x < 0.5 {
+        (1.0 - ease_out_bounce(1.0 - 2.0 * x)) / 2.0
+    } else {
+        (1.0 + ease_out_bounce(2.0 * x - 1.0)) / 2.0
+    }
+}
+
+/// Every easing with its name, to tell them apart when debugging: function
+/// pointers only print as addresses.
+pub(crate) const EASINGS: [(&str, Easing); 19] = [
+    ("linear", linear),
+    ("ease_in_sine", ease_in_sine),
+    ("ease_out_sine", ease_out_sine),
+    ("ease_in_out_sine", ease_in_out_sine),
+    ("ease_in_quad", ease_in_quad),
+    ("ease_out_quad", ease_out_quad),
+    ("ease_in_out_quad", ease_in_out_quad),
+    ("ease_in_cubic", ease_in_cubic),
+    ("ease_out_cubic", ease_out_cubic),
+    ("ease_in_out_cubic", ease_in_out_cubic),
+    ("ease_in_back", ease_in_back),
+    ("ease_out_back", ease_out_back),
+    ("ease_in_out_back", ease_in_out_back),
+    ("ease_in_elastic", ease_in_elastic),
+    ("ease_out_elastic", ease_out_elastic),
+    ("ease_in_out_elastic", ease_in_out_elastic),
+    ("ease_in_bounce", ease_in_bounce),
+    ("ease_out_bounce", ease_out_bounce),
+    ("ease_in_out_bounce", ease_in_out_bounce),
+];
+
+/// The name of `easing` in `EASINGS`, or "custom".
+pub(crate) fn name(easing: Easing) -> &'static str {
+    EASINGS
+        .iter()
+        .find(|(_, known)| std::ptr::fn_addr_eq(*known, easing))
+        .map_or("custom", |(name, _)| name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples() -> impl Iterator<Item = f32> {
+        (0..=100).map(|index| index as f32 / 100.0)
+    }
+
+    #[test]
+    fn easings_keep_their_endpoints() {
+        for (name, easing) in EASINGS {
+            assert!(easing(0.0).abs() < 1e-5, "{name}(0) = {}", easing(0.0));
+            assert!(
+                (easing(1.0) - 1.0).abs() < 1e-5,
+                "{name}(1) = {}",
+                easing(1.0)
+            );
+        }
+    }
+
+    #[test]
+    fn back_and_elastic_easings_overshoot() {
+        for (name, easing) in EASINGS {
+            let overshoots = samples().any(|x| !(-1e-5..=1.0 + 1e-5).contains(&easing(x)));
+            let expected = name.contains("back") || name.contains("elastic");
+            assert_eq!(overshoots, expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn easings_have_their_name() {
+        assert_eq!(name(ease_out_bounce), "ease_out_bounce");
+        assert_eq!(name(|x| x * x * x * x), "custom");
+    }
+}
@@ -0,0 +1,204 @@
+use std::fs::{self, File};
+use std::io::{BufWriter, Cursor};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::imageops::{resize, FilterType};
+use image::{Delay, Frame, ImageOutputFormat, RgbaImage};
+
+use super::renderer::CpuRenderer;
+use super::{MyWindowHandler, RunOptions, State};
+
+/// How long the final screen stays in the export once the state is finished.
+const FINAL_SCREEN_SECONDS: u32 = 3;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum ExportFormat {
+    /// One numbered PNG per frame, in a directory.
+    PngFrames,
+    Gif,
+    Apng,
+}
+
+impl ExportFormat {
+    fn default_fps(self) -> u32 {
+        match self {
+            // GIF delays are in hundredths of a second and most viewers slow
+            // down anything faster than 50 fps.
+            ExportFormat::Gif => 25,
+            ExportFormat::PngFrames | ExportFormat::Apng => 60,
+        }
+    }
+}
+
+/// Renders the state headlessly and writes every frame in the requested
+/// format. The clock is fed with the frame number so the animation doesn't
+/// depend on how long a frame takes to render.
+pub(crate) fn export<S: State>(mut my_window: MyWindowHandler<S>, options: &RunOptions) {
+    let (format, path) = options.export.clone().unwrap();
+    let fps = options.fps.unwrap_or(format.default_fps());
+
+    let mut sink: Box<dyn FrameSink> = match format {
+        ExportFormat::PngFrames => Box::new(PngFrames::new(path.clone())),
+        ExportFormat::Gif => Box::new(Gif::new(&path, fps, options.palette_speed)),
+        ExportFormat::Apng => Box::new(Apng::new(path.clone(), fps)),
+    };
+
+    let mut renderer = CpuRenderer::new(options.layout);
+    let mut frame: u64 = 0;
+    let mut final_frames = 0;
+    let max_frames = options.max_seconds as u64 * fps as u64;
+
+    while final_frames < fps * FINAL_SCREEN_SECONDS {
+        if frame == max_frames {
+            eprintln!(
+                "The visualization didn't finish in {} seconds, the export stops there (see --max-seconds).",
+                options.max_seconds
+            );
+            break;
+        }
+
+        if frame > 0 {
+            my_window.tick(frame_time(frame, fps) - frame_time(frame - 1, fps));
+        }
+        my_window.draw(&mut renderer);
+
+        sink.write_frame(frame, downscale(&renderer.buffer, options.scale));
+
+        frame += 1;
+        if my_window.state.is_finished() {
+            final_frames += 1;
+        }
+    }
+
+    sink.finish();
+
+    println!("{frame} frames written to {}", path.display());
+    for (name, stats) in my_window.text_manager.cache_stats() {
+        println!("Text cache, {name}: {stats}");
+    }
+}
+
+fn frame_time(frame: u64, fps: u32) -> Duration {
+    Duration::from_nanos(frame * 1_000_000_000 / fps as u64)
+}
+
+fn downscale(buffer: &RgbaImage, scale: f32) -> RgbaImage {
+    if scale == 1.0 {
+        return buffer.clone();
+    }
+
+    resize(
+        buffer,
+        (buffer.width() as f32 / scale).round() as u32,
+        (buffer.height() as f32 / scale).round() as u32,
+        FilterType::Triangle,
+    )
+}
+
+trait FrameSink {
+    fn write_frame(&mut self, index: u64, frame: RgbaImage);
+
+    fn finish(self: Box<Self>);
+}
+
+struct PngFrames {
+    directory: PathBuf,
+}
+
+impl PngFrames {
+    fn new(directory: PathBuf) -> Self {
+        fs::create_dir_all(&directory).unwrap();
+
+        Self { directory }
+    }
+}
+
+impl FrameSink for PngFrames {
+    fn write_frame(&mut self, index: u64, frame: RgbaImage) {
+        frame
+            .save(self.directory.join(format!("{index:06}.png")))
+            .unwrap();
+    }
+
+    fn finish(self: Box<Self>) {}
+}
+
+struct Gif {
+    encoder: GifEncoder<BufWriter<File>>,
+    fps: u32,
+}
+
+impl Gif {
+    fn new(path: &Path, fps: u32, palette_speed: i32) -> Self {
+        let file = BufWriter::new(File::create(path).unwrap());
+        let mut encoder = GifEncoder::new_with_speed(file, palette_speed);
+        encoder.set_repeat(Repeat::Infinite).unwrap();
+
+        Self { encoder, fps }
+    }
+}
+
+impl FrameSink for Gif {
+    fn write_frame(&mut self, _index: u64, frame: RgbaImage) {
+        self.encoder
+            .encode_frame(Frame::from_parts(
+                frame,
+                0,
+                0,
+                Delay::from_numer_denom_ms(1000, self.fps),
+            ))
+            .unwrap();
+    }
+
+    fn finish(self: Box<Self>) {}
+}
+
+/// The APNG header needs the number of frames, which is only known once the
+/// state is finished: frames are kept in memory (PNG compressed) until then.
+struct Apng {
+    path: PathBuf,
+    fps: u32,
+    size: (u32, u32),
+    frames: Vec<Vec<u8>>,
+}
+
+impl Apng {
+    fn new(path: PathBuf, fps: u32) -> Self {
+        Self {
+            path,
+            fps,
+            size: (0, 0),
+            frames: vec![],
+        }
+    }
+}
+
+impl FrameSink for Apng {
+    fn write_frame(&mut self, _index: u64, frame: RgbaImage) {
+        self.size = frame.dimensions();
+
+        let mut bytes = vec![];
+        frame
+            .write_to(&mut Cursor::new(&mut bytes), ImageOutputFormat::Png)
+            .unwrap();
+        self.frames.push(bytes);
+    }
+
+    fn finish(self: Box<Self>) {
+        let file = BufWriter::new(File::create(&self.path).unwrap());
+        let mut encoder = png::Encoder::new(file, self.size.0, self.size.1);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_animated(self.frames.len() as u32, 0).unwrap();
+        encoder.set_frame_delay(1, self.fps as u16).unwrap();
+
+        let mut writer = encoder.write_header().unwrap();
+        for bytes in &self.frames {
+            let frame = image::load_from_memory(bytes).unwrap().to_rgba8();
+            writer.write_image_data(&frame).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+}
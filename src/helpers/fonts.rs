@@ -0,0 +1,181 @@
+use std::{env, fs};
+
+use rusttype::{point, Font, GlyphId, Point, PositionedGlyph, Scale, VMetrics};
+
+use super::text::{FontStyle, Weight};
+
+/// Every static font of the bundled family, with the style it draws. The
+/// variable fonts aren't used: rusttype can't pick their weight.
+const FAMILY: [(FontStyle, &[u8]); 16] = [
+    (
+        FontStyle::new(Weight::ExtraLight),
+        include_bytes!("../../data/SourceCodePro-ExtraLight.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::ExtraLight).italic(),
+        include_bytes!("../../data/SourceCodePro-ExtraLightItalic.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::Light),
+        include_bytes!("../../data/SourceCodePro-Light.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::Light).italic(),
+        include_bytes!("../../data/SourceCodePro-LightItalic.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::Regular),
+        include_bytes!("../../data/SourceCodePro-Regular.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::Regular).italic(),
+        include_bytes!("../../data/SourceCodePro-Italic.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::Medium),
+        include_bytes!("../../data/SourceCodePro-Medium.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::Medium).italic(),
+        include_bytes!("../../data/SourceCodePro-MediumItalic.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::SemiBold),
+        include_bytes!("../../data/SourceCodePro-SemiBold.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::SemiBold).italic(),
+        include_bytes!("../../data/SourceCodePro-SemiBoldItalic.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::Bold),
+        include_bytes!("../../data/SourceCodePro-Bold.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::Bold).italic(),
+        include_bytes!("../../data/SourceCodePro-BoldItalic.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::ExtraBold),
+        include_bytes!("../../data/SourceCodePro-ExtraBold.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::ExtraBold).italic(),
+        include_bytes!("../../data/SourceCodePro-ExtraBoldItalic.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::Black),
+        include_bytes!("../../data/SourceCodePro-Black.ttf"),
+    ),
+    (
+        FontStyle::new(Weight::Black).italic(),
+        include_bytes!("../../data/SourceCodePro-BlackItalic.ttf"),
+    ),
+];
+
+/// Fonts for the characters missing from the bundled family, tried in order
+/// when they are installed.
+const SYSTEM_FALLBACK_FONTS: [&str; 6] = [
+    "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+    "/usr/share/fonts/TTF/DejaVuSans.ttf",
+    "/usr/share/fonts/truetype/noto/NotoSansSymbols-Regular.ttf",
+    "/usr/share/fonts/truetype/noto/NotoSansSymbols2-Regular.ttf",
+    "/usr/share/fonts/truetype/noto/NotoEmoji-Regular.ttf",
+    "/usr/share/fonts/noto/NotoEmoji-Regular.ttf",
+];
+/// More fallback fonts, separated by `:`, tried before the system ones.
+const ENV_VARIABLE: &str = "AOC_FALLBACK_FONTS";
+
+/// A glyph and the index of the font it comes from in its `FontChain`.
+pub(crate) type ChainGlyph = (usize, PositionedGlyph<'static>);
+
+/// The bundled family, Source Code Pro, then fallback fonts for the
+/// characters it lacks, e.g. symbols or emoji. Each line is drawn in a style
+/// of the family, characters found in none of the fonts with its missing
+/// glyph. Lines are laid out on the metrics of the style.
+pub(crate) struct FontChain {
+    /// The family first, in the order of `FAMILY`, then the fallbacks.
+    fonts: Vec<Font<'static>>,
+}
+
+impl FontChain {
+    /// Fallback fonts which can't be read are skipped.
+    pub(crate) fn new() -> Self {
+        let from_env = env::var(ENV_VARIABLE).unwrap_or_default();
+        let paths = from_env
+            .split(':')
+            .filter(|path| !path.is_empty())
+            .chain(SYSTEM_FALLBACK_FONTS);
+
+        let fallbacks = paths.filter_map(|path| Font::try_from_vec(fs::read(path).ok()?));
+
+        let family =
+            FAMILY.map(|(_, data)| Font::try_from_bytes(data).expect("Error constructing Font"));
+
+        FontChain {
+            fonts: family.into_iter().chain(fallbacks).collect(),
+        }
+    }
+
+    pub(crate) fn font(&self, index: usize) -> &Font<'static> {
+        &self.fonts[index]
+    }
+
+    pub(crate) fn v_metrics(&self, scale: Scale, style: FontStyle) -> VMetrics {
+        self.fonts[family_index(style)].v_metrics(scale)
+    }
+
+    /// The font of `style` if it has a glyph for `character`, else the first
+    /// fallback which has one.
+    fn glyph_font(&self, character: char, style: FontStyle) -> usize {
+        let primary = family_index(style);
+        let has_glyph = |index: &usize| self.fonts[*index].glyph(character).id() != GlyphId(0);
+
+        [primary]
+            .into_iter()
+            .chain(FAMILY.len()..self.fonts.len())
+            .find(has_glyph)
+            .unwrap_or(primary)
+    }
+
+    /// A single line, its baseline starting at `start`. Kerning only applies
+    /// between glyphs of the same font.
+    pub(crate) fn layout(
+        &self,
+        text: &str,
+        scale: Scale,
+        start: Point<f32>,
+        style: FontStyle,
+    ) -> Vec<ChainGlyph> {
+        let mut caret = start.x;
+        let mut previous: Option<(usize, GlyphId)> = None;
+
+        text.chars()
+            .filter(|character| !character.is_control())
+            .map(|character| {
+                let index = self.glyph_font(character, style);
+                let glyph = self.fonts[index].glyph(character).scaled(scale);
+
+                if let Some((previous_index, previous_id)) = previous {
+                    if previous_index == index {
+                        caret += self.fonts[index].pair_kerning(scale, previous_id, glyph.id());
+                    }
+                }
+                previous = Some((index, glyph.id()));
+
+                let advance_width = glyph.h_metrics().advance_width;
+                let glyph = glyph.positioned(point(caret, start.y));
+                caret += advance_width;
+
+                (index, glyph)
+            })
+            .collect()
+    }
+}
+
+fn family_index(style: FontStyle) -> usize {
+    FAMILY
+        .iter()
+        .position(|(family_style, _)| *family_style == style)
+        .unwrap()
+}
@@ -0,0 +1,130 @@
+use speedy2d::dimen::Vector2;
+use speedy2d::shape::Rectangle;
+
+/// The canvas the days draw on. Positions are computed from it instead of
+/// fixed pixels, so the same visualization renders for shorts (portrait)
+/// and for regular videos (landscape).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Layout {
+    width: u32,
+    height: u32,
+}
+
+impl Layout {
+    /// 9:16, for shorts.
+    pub(crate) const PORTRAIT: Layout = Layout {
+        width: 810,
+        height: 1440,
+    };
+    /// 16:9, for regular videos.
+    pub(crate) const LANDSCAPE: Layout = Layout {
+        width: 1440,
+        height: 810,
+    };
+
+    /// `portrait`, `landscape` or `<width>x<height>`.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "portrait" => Some(Self::PORTRAIT),
+            "landscape" => Some(Self::LANDSCAPE),
+            size => {
+                let (width, height) = size.split_once('x')?;
+
+                Some(Layout {
+                    width: width.parse().ok().filter(|width| *width > 0)?,
+                    height: height.parse().ok().filter(|height| *height > 0)?,
+                })
+            }
+        }
+    }
+
+    pub(crate) fn size(&self) -> Vector2<u32> {
+        Vector2::new(self.width, self.height)
+    }
+
+    pub(crate) fn width(&self) -> f32 {
+        self.width as f32
+    }
+
+    pub(crate) fn height(&self) -> f32 {
+        self.height as f32
+    }
+
+    pub(crate) fn is_landscape(&self) -> bool {
+        self.width > self.height
+    }
+
+    /// Splits the canvas in a header, for the texts, and a stage: the header
+    /// is the top `ratio` of the canvas in portrait and its left `ratio` in
+    /// landscape.
+    pub(crate) fn split(&self, ratio: f32) -> (Rectangle, Rectangle) {
+        let (width, height) = (self.width(), self.height());
+
+        if self.is_landscape() {
+            (
+                Rectangle::new(Vector2::ZERO, Vector2::new(width * ratio, height)),
+                Rectangle::new(
+                    Vector2::new(width * ratio, 0.0),
+                    Vector2::new(width, height),
+                ),
+            )
+        } else {
+            (
+                Rectangle::new(Vector2::ZERO, Vector2::new(width, height * ratio)),
+                Rectangle::new(
+                    Vector2::new(0.0, height * ratio),
+                    Vector2::new(width, height),
+                ),
+            )
+        }
+    }
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self::PORTRAIT
+    }
+}
+
+/// Where the canvas lands in the window: scaled to fit and centered, with
+/// bars on the sides that don't match its aspect ratio.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Viewport {
+    scale: f32,
+    offset: Vector2<f32>,
+}
+
+impl Viewport {
+    /// A window with no pixels, e.g. minimized, is taken as one pixel wide
+    /// and high, so the scale never gets to 0.
+    pub(crate) fn fit(layout: Layout, window_size: Vector2<u32>) -> Self {
+        let window_size = Vector2::new(window_size.x.max(1) as f32, window_size.y.max(1) as f32);
+        let scale = (window_size.x / layout.width()).min(window_size.y / layout.height());
+
+        Viewport {
+            scale,
+            offset: (window_size - Vector2::new(layout.width(), layout.height()) * scale) / 2.0,
+        }
+    }
+
+    /// Window pixels per pixel of the canvas.
+    pub(crate) fn scale(self) -> f32 {
+        self.scale
+    }
+
+    pub(crate) fn to_window(self, point: Vector2<f32>) -> Vector2<f32> {
+        point * self.scale + self.offset
+    }
+
+    pub(crate) fn to_canvas(self, point: Vector2<f32>) -> Vector2<f32> {
+        (point - self.offset) / self.scale
+    }
+
+    /// The canvas, in window pixels.
+    pub(crate) fn canvas_rect(self, layout: Layout) -> Rectangle {
+        Rectangle::new(
+            self.to_window(Vector2::ZERO),
+            self.to_window(Vector2::new(layout.width(), layout.height())),
+        )
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::Hash;
+
+/// How well a cache does, since it was created.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct CacheStats {
+    pub(crate) hits: u64,
+    pub(crate) misses: u64,
+    pub(crate) evictions: u64,
+    /// Bytes held, and how many it may hold.
+    pub(crate) used: usize,
+    pub(crate) budget: usize,
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {} evictions, {:.1} / {:.1} MB",
+            self.hits,
+            self.misses,
+            self.evictions,
+            self.used as f32 / MEGABYTE as f32,
+            self.budget as f32 / MEGABYTE as f32
+        )
+    }
+}
+
+pub(crate) const MEGABYTE: usize = 1024 * 1024;
+
+struct Entry<V> {
+    value: V,
+    cost: usize,
+    last_used: u64,
+}
+
+/// A map holding at most `budget` bytes, as counted by `cost`. Once over
+/// budget, the least recently used values are dropped. A single value bigger
+/// than the budget is still kept, until the next one comes.
+pub(crate) struct LruCache<K, V> {
+    entries: HashMap<K, Entry<V>>,
+    /// The keys by last use, the least recently used first.
+    order: BTreeMap<u64, K>,
+    tick: u64,
+    cost: fn(&V) -> usize,
+    stats: CacheStats,
+}
+
+impl<K: Clone + Eq + Hash, V> LruCache<K, V> {
+    pub(crate) fn new(budget: usize, cost: fn(&V) -> usize) -> Self {
+        LruCache {
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+            cost,
+            stats: CacheStats {
+                budget,
+                ..CacheStats::default()
+            },
+        }
+    }
+
+    pub(crate) fn get_or_insert_with(&mut self, key: K, make: impl FnOnce() -> V) -> &V {
+        self.tick += 1;
+
+        if let Some(entry) = self.entries.get_mut(&key) {
+            self.stats.hits += 1;
+            self.order.remove(&entry.last_used);
+            entry.last_used = self.tick;
+        } else {
+            self.stats.misses += 1;
+            let value = make();
+            let cost = (self.cost)(&value);
+            self.stats.used += cost;
+            self.entries.insert(
+                key.clone(),
+                Entry {
+                    value,
+                    cost,
+                    last_used: self.tick,
+                },
+            );
+            self.evict();
+        }
+        self.order.insert(self.tick, key.clone());
+
+        &self.entries[&key].value
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Drops the least recently used values until back under budget, the
+    /// value just used (at the current tick) excepted.
+    fn evict(&mut self) {
+        while self.stats.used > self.stats.budget {
+            let Some((_, key)) = self.order.pop_first() else {
+                break;
+            };
+
+            let entry = self.entries.remove(&key).unwrap();
+            self.stats.used -= entry.cost;
+            self.stats.evictions += 1;
+        }
+    }
+}
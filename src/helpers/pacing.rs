@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+/// How long each item of a sequence lasts: the first ones slowly, so the
+/// viewer gets what happens, then fast-forward through the rest, possibly
+/// slowing down again for the last ones.
+#[derive(Clone, Debug)]
+pub(crate) struct Pacing {
+    /// Phases of `(number of items, duration of each item)`, from the first item.
+    head: Vec<(usize, Duration)>,
+    rest: Duration,
+    /// Phases of the last items, in the order they are played.
+    tail: Vec<(usize, Duration)>,
+}
+
+impl Pacing {
+    /// Every item lasts `rest`, unless a phase says otherwise.
+    pub(crate) fn new(rest: Duration) -> Self {
+        Pacing {
+            head: vec![],
+            rest,
+            tail: vec![],
+        }
+    }
+
+    /// The next `count` items from the start last `duration` each.
+    pub(crate) fn first(mut self, count: usize, duration: Duration) -> Self {
+        self.head.push((count, duration));
+        self
+    }
+
+    /// Adds `count` items lasting `duration` each to the tail, which is made
+    /// of the `last` phases in order and ends with the sequence.
+    pub(crate) fn last(mut self, count: usize, duration: Duration) -> Self {
+        self.tail.push((count, duration));
+        self
+    }
+
+    /// The first phases win over the last ones when there are few items.
+    pub(crate) fn duration(&self, index: usize, count: usize) -> Duration {
+        if let Some(duration) = phase_duration(&self.head, index) {
+            return duration;
+        }
+
+        let tail_count: usize = self.tail.iter().map(|(count, _)| count).sum();
+        if index + tail_count >= count {
+            if let Some(duration) = phase_duration(&self.tail, index + tail_count - count) {
+                return duration;
+            }
+        }
+
+        self.rest
+    }
+}
+
+fn phase_duration(phases: &[(usize, Duration)], mut index: usize) -> Option<Duration> {
+    for (count, duration) in phases {
+        if index < *count {
+            return Some(*duration);
+        }
+        index -= count;
+    }
+
+    None
+}
+
+/// The item being played at a given time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Step {
+    pub(crate) index: usize,
+    /// From 0 when the item starts to 1 when it ends.
+    pub(crate) progress: f32,
+    /// When the item started, to start animations with it.
+    pub(crate) start: Duration,
+    pub(crate) duration: Duration,
+}
+
+/// Plays items one after the other, paced by a `Pacing`. It only depends on
+/// the time since the start, so seeking and exporting see the same steps.
+#[derive(Clone, Debug)]
+pub(crate) struct Sequencer {
+    /// When each item ends.
+    ends: Vec<Duration>,
+}
+
+impl Sequencer {
+    pub(crate) fn new(pacing: &Pacing, count: usize) -> Self {
+        Self::weighted(pacing, &vec![1.0; count])
+    }
+
+    /// Each item lasts its duration in `pacing` times its weight, for items
+    /// made of several steps.
+    pub(crate) fn weighted(pacing: &Pacing, weights: &[f32]) -> Self {
+        let mut end = Duration::ZERO;
+        let ends = weights
+            .iter()
+            .enumerate()
+            .map(|(index, weight)| {
+                end += pacing.duration(index, weights.len()).mul_f32(*weight);
+                end
+            })
+            .collect();
+
+        Sequencer { ends }
+    }
+
+    /// `None` once every item has been played. Items too short to be seen
+    /// between two frames are skipped, callers catch up on them.
+    pub(crate) fn at(&self, elapsed: Duration) -> Option<Step> {
+        let index = self.ends.partition_point(|end| *end <= elapsed);
+        let end = *self.ends.get(index)?;
+        let start = match index {
+            0 => Duration::ZERO,
+            _ => self.ends[index - 1],
+        };
+
+        Some(Step {
+            index,
+            progress: (elapsed - start).as_secs_f32() / (end - start).as_secs_f32(),
+            start,
+            duration: end - start,
+        })
+    }
+}
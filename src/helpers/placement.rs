@@ -0,0 +1,63 @@
+use std::env;
+use std::process::Command;
+
+/// The window class, which window manager rules match on.
+pub(crate) const WINDOW_CLASS: &str = "aoc";
+/// Chooses the placement when `--placement` isn't given.
+const ENV_VARIABLE: &str = "AOC_WINDOW_PLACEMENT";
+
+/// Runs right before the window opens, so the rules it sets up apply to the
+/// new window. Placing a window is a nicety: failures are only reported.
+pub(crate) trait WindowPlacement {
+    fn place(&self, class: &str);
+}
+
+/// Leaves the window to the window manager.
+pub(crate) struct NoPlacement;
+
+impl WindowPlacement for NoPlacement {
+    fn place(&self, _class: &str) {}
+}
+
+/// bspwm: a floating window on the `Term` desktop.
+pub(crate) struct Bspc;
+
+impl WindowPlacement for Bspc {
+    fn place(&self, class: &str) {
+        run(Command::new("bspc").args(["rule", "-a", class, "desktop='Term'", "state=floating"]));
+    }
+}
+
+/// Any other window manager: a shell command, with the window class in
+/// `$AOC_WINDOW_CLASS`.
+pub(crate) struct ShellCommand(pub(crate) String);
+
+impl WindowPlacement for ShellCommand {
+    fn place(&self, class: &str) {
+        run(Command::new("sh")
+            .args(["-c", &self.0])
+            .env("AOC_WINDOW_CLASS", class));
+    }
+}
+
+fn run(command: &mut Command) {
+    match command.status() {
+        Ok(status) if status.success() => {}
+        Ok(status) => eprintln!("Window placement failed: {command:?} exited with {status}"),
+        Err(error) => eprintln!("Window placement failed: cannot run {command:?}: {error}"),
+    }
+}
+
+/// `none`, `bspc`, or a shell command.
+pub(crate) fn from_name(name: &str) -> Box<dyn WindowPlacement> {
+    match name {
+        "" | "none" => Box::new(NoPlacement),
+        "bspc" => Box::new(Bspc),
+        command => Box::new(ShellCommand(command.to_string())),
+    }
+}
+
+/// The placement chosen by the environment, none by default.
+pub(crate) fn from_env() -> Box<dyn WindowPlacement> {
+    from_name(&env::var(ENV_VARIABLE).unwrap_or_default())
+}
@@ -0,0 +1,390 @@
+use std::rc::Rc;
+
+use image::{ImageBuffer, Rgba, RgbaImage};
+use speedy2d::color::Color;
+use speedy2d::dimen::Vector2;
+use speedy2d::image::{ImageDataType, ImageHandle, ImageSmoothingMode};
+use speedy2d::shape::Rectangle;
+use speedy2d::Graphics2D;
+
+use super::layout::{Layout, Viewport};
+
+/// An image uploaded to a `Renderer`. A texture can only be drawn by the
+/// backend which created it.
+#[derive(Clone)]
+pub(crate) enum Texture {
+    Gpu(ImageHandle),
+    Cpu(Rc<RgbaImage>),
+    /// Created by the `NullRenderer`, only its size is known.
+    Null(Vector2<u32>),
+}
+
+impl Texture {
+    pub(crate) fn size(&self) -> Vector2<u32> {
+        match self {
+            Texture::Gpu(handle) => *handle.size(),
+            Texture::Cpu(image) => Vector2::new(image.width(), image.height()),
+            Texture::Null(size) => *size,
+        }
+    }
+}
+
+/// Everything a `State` can draw. Quads are given clockwise, starting from the
+/// top left corner of the image.
+pub(crate) trait Renderer {
+    /// The canvas drawn on, days lay themselves out from it.
+    fn layout(&self) -> Layout;
+
+    /// How much the drawings are scaled on the canvas: texts are rasterized
+    /// at their size times this, so they stay sharp when zoomed.
+    fn text_scale(&self) -> f32 {
+        1.0
+    }
+
+    fn clear_screen(&mut self, color: Color);
+
+    /// `pixels` are RGBA, row by row.
+    fn create_image_from_raw_pixels(&mut self, size: (u32, u32), pixels: &[u8]) -> Texture;
+
+    fn create_image_from_file_path(&mut self, path: &str) -> Texture;
+
+    fn draw_quad(&mut self, quad: [Vector2<f32>; 4], color: Color);
+
+    fn draw_quad_image(&mut self, quad: [Vector2<f32>; 4], texture: &Texture) {
+        self.draw_quad_image_tinted(quad, texture, Color::WHITE);
+    }
+
+    /// The pixels of the texture are multiplied by `tint`, e.g. a transparent
+    /// white to fade it.
+    fn draw_quad_image_tinted(&mut self, quad: [Vector2<f32>; 4], texture: &Texture, tint: Color) {
+        self.draw_quad_image_region(
+            quad,
+            texture,
+            Rectangle::new(Vector2::ZERO, Vector2::new(1.0, 1.0)),
+            tint,
+        );
+    }
+
+    /// Draws only the `region` of the texture, in coordinates normalized from
+    /// 0 to 1, e.g. a sprite of an atlas.
+    fn draw_quad_image_region(
+        &mut self,
+        quad: [Vector2<f32>; 4],
+        texture: &Texture,
+        region: Rectangle,
+        tint: Color,
+    );
+
+    fn draw_rectangle_image(&mut self, rectangle: Rectangle, texture: &Texture) {
+        self.draw_quad_image(
+            [
+                *rectangle.top_left(),
+                rectangle.top_right(),
+                *rectangle.bottom_right(),
+                rectangle.bottom_left(),
+            ],
+            texture,
+        );
+    }
+
+    fn draw_image(&mut self, position: Vector2<f32>, texture: &Texture) {
+        let size = texture.size();
+        self.draw_rectangle_image(
+            Rectangle::new(
+                position,
+                position + Vector2::new(size.x as f32, size.y as f32),
+            ),
+            texture,
+        );
+    }
+}
+
+/// Draws into the OpenGL window through speedy2d, the canvas being scaled to
+/// the window by `viewport`. Nothing is drawn over the bars around it.
+pub(crate) struct SpeedyRenderer<'a> {
+    graphics: &'a mut Graphics2D,
+    layout: Layout,
+    viewport: Viewport,
+}
+
+impl<'a> SpeedyRenderer<'a> {
+    pub(crate) fn new(graphics: &'a mut Graphics2D, layout: Layout, viewport: Viewport) -> Self {
+        let mut renderer = Self {
+            graphics,
+            layout,
+            viewport,
+        };
+        renderer.clip_to_canvas();
+
+        renderer
+    }
+
+    fn clip_to_canvas(&mut self) {
+        let canvas = self.viewport.canvas_rect(self.layout);
+
+        self.graphics.set_clip(Some(Rectangle::new(
+            Vector2::new(canvas.top_left().x.round(), canvas.top_left().y.round()).into_i32(),
+            Vector2::new(
+                canvas.bottom_right().x.round(),
+                canvas.bottom_right().y.round(),
+            )
+            .into_i32(),
+        )));
+    }
+
+    fn quad_to_window(&self, quad: [Vector2<f32>; 4]) -> [Vector2<f32>; 4] {
+        quad.map(|point| self.viewport.to_window(point))
+    }
+}
+
+fn gpu_handle(texture: &Texture) -> &ImageHandle {
+    match texture {
+        Texture::Gpu(handle) => handle,
+        _ => panic!("texture drawn with the wrong renderer"),
+    }
+}
+
+impl Renderer for SpeedyRenderer<'_> {
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Texts are rasterized for the window, not stretched from the canvas.
+    fn text_scale(&self) -> f32 {
+        self.viewport.scale()
+    }
+
+    fn clear_screen(&mut self, color: Color) {
+        self.graphics.set_clip(None);
+        self.graphics.clear_screen(Color::BLACK);
+        self.clip_to_canvas();
+
+        self.graphics
+            .draw_rectangle(self.viewport.canvas_rect(self.layout), color);
+    }
+
+    fn create_image_from_raw_pixels(&mut self, size: (u32, u32), pixels: &[u8]) -> Texture {
+        Texture::Gpu(
+            self.graphics
+                .create_image_from_raw_pixels(
+                    ImageDataType::RGBA,
+                    ImageSmoothingMode::NearestNeighbor,
+                    size,
+                    pixels,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn create_image_from_file_path(&mut self, path: &str) -> Texture {
+        Texture::Gpu(
+            self.graphics
+                .create_image_from_file_path(None, ImageSmoothingMode::NearestNeighbor, path)
+                .unwrap(),
+        )
+    }
+
+    fn draw_quad(&mut self, quad: [Vector2<f32>; 4], color: Color) {
+        self.graphics.draw_quad(self.quad_to_window(quad), color);
+    }
+
+    fn draw_quad_image_region(
+        &mut self,
+        quad: [Vector2<f32>; 4],
+        texture: &Texture,
+        region: Rectangle,
+        tint: Color,
+    ) {
+        self.graphics.draw_quad_image_tinted_four_color(
+            self.quad_to_window(quad),
+            [tint, tint, tint, tint],
+            [
+                *region.top_left(),
+                region.top_right(),
+                *region.bottom_right(),
+                region.bottom_left(),
+            ],
+            gpu_handle(texture),
+        );
+    }
+}
+
+/// Software rasterizer drawing into an `ImageBuffer`, so frames can be
+/// produced without any window or GPU.
+pub(crate) struct CpuRenderer {
+    pub(crate) buffer: RgbaImage,
+    layout: Layout,
+}
+
+impl CpuRenderer {
+    pub(crate) fn new(layout: Layout) -> Self {
+        Self {
+            buffer: ImageBuffer::new(layout.size().x, layout.size().y),
+            layout,
+        }
+    }
+
+    /// Pixels whose center is inside the bounding box of the quad, clipped
+    /// to the buffer.
+    fn pixels_around(&self, quad: &[Vector2<f32>; 4]) -> impl Iterator<Item = (u32, u32)> {
+        let min_x = quad.iter().map(|v| v.x).fold(f32::INFINITY, f32::min);
+        let max_x = quad.iter().map(|v| v.x).fold(f32::NEG_INFINITY, f32::max);
+        let min_y = quad.iter().map(|v| v.y).fold(f32::INFINITY, f32::min);
+        let max_y = quad.iter().map(|v| v.y).fold(f32::NEG_INFINITY, f32::max);
+
+        let from_x = min_x.floor().clamp(0.0, self.buffer.width() as f32) as u32;
+        let to_x = max_x.ceil().clamp(0.0, self.buffer.width() as f32) as u32;
+        let from_y = min_y.floor().clamp(0.0, self.buffer.height() as f32) as u32;
+        let to_y = max_y.ceil().clamp(0.0, self.buffer.height() as f32) as u32;
+
+        (from_y..to_y).flat_map(move |y| (from_x..to_x).map(move |x| (x, y)))
+    }
+
+    fn blend(&mut self, x: u32, y: u32, color: Rgba<u8>) {
+        let alpha = color.0[3] as f32 / 255.0;
+        if alpha <= 0.0 {
+            return;
+        }
+
+        let pixel = self.buffer.get_pixel_mut(x, y);
+        for channel in 0..3 {
+            pixel.0[channel] = (color.0[channel] as f32 * alpha
+                + pixel.0[channel] as f32 * (1.0 - alpha))
+                .round() as u8;
+        }
+        pixel.0[3] = (color.0[3] as f32 + pixel.0[3] as f32 * (1.0 - alpha)).round() as u8;
+    }
+}
+
+fn cross(a: Vector2<f32>, b: Vector2<f32>) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+fn color_to_rgba(color: Color) -> Rgba<u8> {
+    Rgba([
+        (color.r() * 255.0).round() as u8,
+        (color.g() * 255.0).round() as u8,
+        (color.b() * 255.0).round() as u8,
+        (color.a() * 255.0).round() as u8,
+    ])
+}
+
+fn tinted(pixel: Rgba<u8>, tint: Color) -> Rgba<u8> {
+    let tint = [tint.r(), tint.g(), tint.b(), tint.a()];
+
+    Rgba(std::array::from_fn(|channel| {
+        (pixel.0[channel] as f32 * tint[channel]).round() as u8
+    }))
+}
+
+impl Renderer for CpuRenderer {
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn clear_screen(&mut self, color: Color) {
+        let color = color_to_rgba(color);
+        for pixel in self.buffer.pixels_mut() {
+            *pixel = color;
+        }
+    }
+
+    fn create_image_from_raw_pixels(&mut self, size: (u32, u32), pixels: &[u8]) -> Texture {
+        Texture::Cpu(Rc::new(
+            ImageBuffer::from_raw(size.0, size.1, pixels.to_vec()).unwrap(),
+        ))
+    }
+
+    fn create_image_from_file_path(&mut self, path: &str) -> Texture {
+        Texture::Cpu(Rc::new(image::open(path).unwrap().to_rgba8()))
+    }
+
+    fn draw_quad(&mut self, quad: [Vector2<f32>; 4], color: Color) {
+        let color = color_to_rgba(color);
+        let pixels: Vec<_> = self.pixels_around(&quad).collect();
+
+        for (x, y) in pixels {
+            let point = Vector2::new(x as f32 + 0.5, y as f32 + 0.5);
+            let sides: Vec<f32> = (0..4)
+                .map(|i| cross(quad[(i + 1) % 4] - quad[i], point - quad[i]))
+                .collect();
+
+            if sides.iter().all(|side| *side >= 0.0) || sides.iter().all(|side| *side <= 0.0) {
+                self.blend(x, y, color);
+            }
+        }
+    }
+
+    fn draw_quad_image_region(
+        &mut self,
+        quad: [Vector2<f32>; 4],
+        texture: &Texture,
+        region: Rectangle,
+        tint: Color,
+    ) {
+        let image = match texture {
+            Texture::Cpu(image) => image.clone(),
+            _ => panic!("texture drawn with the wrong renderer"),
+        };
+
+        // Quads drawn with an image are always parallelograms (rotated or not), so
+        // the texture coordinates are an affine function of the position.
+        let axis_u = quad[1] - quad[0];
+        let axis_v = quad[3] - quad[0];
+        let determinant = cross(axis_u, axis_v);
+        if determinant.abs() < f32::EPSILON {
+            return;
+        }
+
+        let pixels: Vec<_> = self.pixels_around(&quad).collect();
+        for (x, y) in pixels {
+            let offset = Vector2::new(x as f32 + 0.5, y as f32 + 0.5) - quad[0];
+            let u = cross(offset, axis_v) / determinant;
+            let v = cross(axis_u, offset) / determinant;
+
+            if !(0.0..1.0).contains(&u) || !(0.0..1.0).contains(&v) {
+                continue;
+            }
+
+            let u = region.top_left().x + u * region.width();
+            let v = region.top_left().y + v * region.height();
+            let source_x = ((u * image.width() as f32) as u32).min(image.width() - 1);
+            let source_y = ((v * image.height() as f32) as u32).min(image.height() - 1);
+            let pixel = image.get_pixel(source_x, source_y);
+            self.blend(x, y, tinted(*pixel, tint));
+        }
+    }
+}
+
+/// Draws nothing. Used to run states forward without showing the frames.
+pub(crate) struct NullRenderer {
+    pub(crate) layout: Layout,
+}
+
+impl Renderer for NullRenderer {
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn clear_screen(&mut self, _color: Color) {}
+
+    fn create_image_from_raw_pixels(&mut self, size: (u32, u32), _pixels: &[u8]) -> Texture {
+        Texture::Null(Vector2::new(size.0, size.1))
+    }
+
+    fn create_image_from_file_path(&mut self, path: &str) -> Texture {
+        let (width, height) = image::image_dimensions(path).unwrap();
+        Texture::Null(Vector2::new(width, height))
+    }
+
+    fn draw_quad(&mut self, _quad: [Vector2<f32>; 4], _color: Color) {}
+
+    fn draw_quad_image_region(
+        &mut self,
+        _quad: [Vector2<f32>; 4],
+        _texture: &Texture,
+        _region: Rectangle,
+        _tint: Color,
+    ) {
+    }
+}
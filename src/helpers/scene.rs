@@ -0,0 +1,248 @@
+use speedy2d::color::Color;
+use speedy2d::dimen::Vector2;
+
+use super::rect_at_position;
+use super::renderer::{Renderer, Texture};
+use super::text::FontStyle;
+use super::{scaled_text_size, TextManager, TextType};
+
+/// A 2D affine transform: where the unit axes and the origin end up.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Affine {
+    x_axis: Vector2<f32>,
+    y_axis: Vector2<f32>,
+    translation: Vector2<f32>,
+}
+
+impl Affine {
+    pub(crate) const IDENTITY: Affine = Affine {
+        x_axis: Vector2::new(1.0, 0.0),
+        y_axis: Vector2::new(0.0, 1.0),
+        translation: Vector2::ZERO,
+    };
+
+    pub(crate) fn translation(translation: Vector2<f32>) -> Self {
+        Affine {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Clockwise on screen, as y goes down.
+    pub(crate) fn rotation(rotation_rad: f32) -> Self {
+        let (sin, cos) = rotation_rad.sin_cos();
+
+        Affine {
+            x_axis: Vector2::new(cos, sin),
+            y_axis: Vector2::new(-sin, cos),
+            translation: Vector2::ZERO,
+        }
+    }
+
+    pub(crate) fn scale(scale: Vector2<f32>) -> Self {
+        Affine {
+            x_axis: Vector2::new(scale.x, 0.0),
+            y_axis: Vector2::new(0.0, scale.y),
+            translation: Vector2::ZERO,
+        }
+    }
+
+    /// `inner` applied first, then `self`: a child transform inside its parent.
+    pub(crate) fn then(self, inner: Affine) -> Self {
+        Affine {
+            x_axis: self.apply_vector(inner.x_axis),
+            y_axis: self.apply_vector(inner.y_axis),
+            translation: self.apply(inner.translation),
+        }
+    }
+
+    pub(crate) fn apply(self, point: Vector2<f32>) -> Vector2<f32> {
+        self.apply_vector(point) + self.translation
+    }
+
+    /// How much areas are scaled, as a single factor.
+    pub(crate) fn scale_factor(self) -> f32 {
+        (self.x_axis.x * self.y_axis.y - self.x_axis.y * self.y_axis.x)
+            .abs()
+            .sqrt()
+    }
+
+    /// Ignores the translation, for directions and sizes.
+    pub(crate) fn apply_vector(self, vector: Vector2<f32>) -> Vector2<f32> {
+        self.x_axis * vector.x + self.y_axis * vector.y
+    }
+}
+
+/// What a node draws, centered on its origin.
+#[derive(Clone)]
+pub(crate) enum Content {
+    Image(Texture),
+    Text {
+        text: String,
+        size: u32,
+        text_type: TextType,
+    },
+    Rect {
+        size: Vector2<f32>,
+        color: Color,
+    },
+    /// Drawn in order, the last child on top.
+    Group(Vec<Node>),
+}
+
+/// A node of a scene graph. Its children are placed in its own coordinates:
+/// they move, turn, scale and fade with it.
+#[derive(Clone)]
+pub(crate) struct Node {
+    pub(crate) translation: Vector2<f32>,
+    /// In radians, around the origin of the node.
+    pub(crate) rotation: f32,
+    pub(crate) scale: Vector2<f32>,
+    /// From 0 (hidden) to 1, multiplied by the one of the parents.
+    pub(crate) opacity: f32,
+    pub(crate) content: Content,
+}
+
+impl Node {
+    pub(crate) fn new(content: Content) -> Self {
+        Node {
+            translation: Vector2::ZERO,
+            rotation: 0.0,
+            scale: Vector2::new(1.0, 1.0),
+            opacity: 1.0,
+            content,
+        }
+    }
+
+    pub(crate) fn image(texture: Texture) -> Self {
+        Self::new(Content::Image(texture))
+    }
+
+    pub(crate) fn text(text: String, size: u32, text_type: TextType) -> Self {
+        Self::new(Content::Text {
+            text,
+            size,
+            text_type,
+        })
+    }
+
+    pub(crate) fn rect(size: Vector2<f32>, color: Color) -> Self {
+        Self::new(Content::Rect { size, color })
+    }
+
+    pub(crate) fn group(children: Vec<Node>) -> Self {
+        Self::new(Content::Group(children))
+    }
+
+    pub(crate) fn at(mut self, translation: Vector2<f32>) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    pub(crate) fn rotated(mut self, rotation_rad: f32) -> Self {
+        self.rotation = rotation_rad;
+        self
+    }
+
+    pub(crate) fn scaled(self, scale: f32) -> Self {
+        self.scaled_xy(Vector2::new(scale, scale))
+    }
+
+    pub(crate) fn scaled_xy(mut self, scale: Vector2<f32>) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub(crate) fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Scaled first, then rotated, then translated.
+    pub(crate) fn transform(&self) -> Affine {
+        Affine::translation(self.translation)
+            .then(Affine::rotation(self.rotation))
+            .then(Affine::scale(self.scale))
+    }
+
+    pub(crate) fn draw(&self, renderer: &mut dyn Renderer, text_manager: &mut TextManager) {
+        self.draw_in(renderer, text_manager, Affine::IDENTITY, 1.0);
+    }
+
+    fn draw_in(
+        &self,
+        renderer: &mut dyn Renderer,
+        text_manager: &mut TextManager,
+        parent: Affine,
+        parent_opacity: f32,
+    ) {
+        let transform = parent.then(self.transform());
+        let opacity = parent_opacity * self.opacity;
+        if opacity <= 0.0 {
+            return;
+        }
+
+        match &self.content {
+            Content::Image(texture) => {
+                let size = texture.size().into_f32();
+                draw_texture(renderer, transform, opacity, texture, size);
+            }
+            Content::Text {
+                text,
+                size,
+                text_type,
+            } => {
+                // Rasterized at the size it is drawn on the canvas.
+                let scale = renderer.text_scale() * transform.scale_factor();
+                let scaled_size = scaled_text_size(*size, scale);
+                let metrics = text_manager.measure(text, scaled_size);
+                let ink_width = match metrics.bounding_box {
+                    Some(bounding_box) => bounding_box.width(),
+                    None => return,
+                };
+
+                // Centered as if drawn on a texture with a margin around the glyphs.
+                let origin =
+                    Vector2::new(-ink_width / 2.0, (metrics.ascent - metrics.descent) / 2.0);
+                text_manager.draw_line(
+                    renderer,
+                    text,
+                    scaled_size,
+                    *text_type,
+                    FontStyle::REGULAR,
+                    transform
+                        .then(Affine::scale(Vector2::new(1.0, 1.0) / scale))
+                        .then(Affine::translation(origin)),
+                    Color::from_rgba(1.0, 1.0, 1.0, opacity),
+                );
+            }
+            Content::Rect { size, color } => {
+                let quad = rect_at_position(Vector2::ZERO, size.x, size.y)
+                    .map(|corner| transform.apply(corner));
+
+                renderer.draw_quad(
+                    quad,
+                    Color::from_rgba(color.r(), color.g(), color.b(), color.a() * opacity),
+                );
+            }
+            Content::Group(children) => {
+                for child in children {
+                    child.draw_in(renderer, text_manager, transform, opacity);
+                }
+            }
+        }
+    }
+}
+
+fn draw_texture(
+    renderer: &mut dyn Renderer,
+    transform: Affine,
+    opacity: f32,
+    texture: &Texture,
+    size: Vector2<f32>,
+) {
+    let quad =
+        rect_at_position(Vector2::ZERO, size.x, size.y).map(|corner| transform.apply(corner));
+
+    renderer.draw_quad_image_tinted(quad, texture, Color::from_rgba(1.0, 1.0, 1.0, opacity));
+}
@@ -0,0 +1,111 @@
+use speedy2d::shape::Rectangle;
+
+/// Where a text is drawn from its position, horizontally.
+#[derive(Clone, Copy)]
+pub(crate) enum Align {
+    Center,
+    Left,
+    Right,
+}
+
+/// Where a text is drawn from its position, vertically.
+#[derive(Clone, Copy)]
+pub(crate) enum VerticalAlign {
+    /// The top of the font, at its ascent.
+    Top,
+    Center,
+    /// The line the glyphs sit on, for texts of different sizes side by side.
+    Baseline,
+    /// The bottom of the font, at its descent.
+    Bottom,
+}
+
+/// The weights of Source Code Pro, from the thinnest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Weight {
+    ExtraLight,
+    Light,
+    Regular,
+    Medium,
+    SemiBold,
+    Bold,
+    ExtraBold,
+    Black,
+}
+
+/// Which font of the family a text is drawn with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct FontStyle {
+    pub(crate) weight: Weight,
+    pub(crate) italic: bool,
+}
+
+impl FontStyle {
+    pub(crate) const REGULAR: FontStyle = FontStyle::new(Weight::Regular);
+
+    pub(crate) const fn new(weight: Weight) -> Self {
+        FontStyle {
+            weight,
+            italic: false,
+        }
+    }
+
+    pub(crate) const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+}
+
+/// How a text is placed around its position, how far apart its lines are,
+/// and which font it is drawn with.
+#[derive(Clone, Copy)]
+pub(crate) struct TextLayout {
+    pub(crate) align: Align,
+    pub(crate) vertical_align: VerticalAlign,
+    /// Distance between two baselines, in line heights of the font.
+    pub(crate) line_spacing: f32,
+    pub(crate) style: FontStyle,
+}
+
+impl TextLayout {
+    pub(crate) fn new(align: Align, vertical_align: VerticalAlign) -> Self {
+        TextLayout {
+            align,
+            vertical_align,
+            line_spacing: 1.0,
+            style: FontStyle::REGULAR,
+        }
+    }
+
+    pub(crate) fn with_style(mut self, style: FontStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub(crate) fn with_line_spacing(mut self, line_spacing: f32) -> Self {
+        self.line_spacing = line_spacing;
+        self
+    }
+}
+
+/// The size of a line of text, in pixels, without the margin around it.
+#[derive(Clone, Debug)]
+pub(crate) struct TextMetrics {
+    /// How far the pen goes: where a text drawn after this one would start.
+    pub(crate) advance_width: f32,
+    /// Height of the font above the baseline.
+    pub(crate) ascent: f32,
+    /// Depth of the font under the baseline, positive.
+    pub(crate) descent: f32,
+    /// Recommended space between the descent of a line and the ascent of the next.
+    pub(crate) line_gap: f32,
+    /// The pixels covered by the glyphs, from the start of the baseline. `None`
+    /// when nothing is drawn, e.g. for spaces.
+    pub(crate) bounding_box: Option<Rectangle>,
+}
+
+impl TextMetrics {
+    pub(crate) fn line_height(&self) -> f32 {
+        self.ascent + self.descent + self.line_gap
+    }
+}
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use super::clock::Clock;
+
+/// Time between two snapshots. Seeking replays at most this much animation.
+const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// History of a run, to go back in time. Every frame delta is kept, and a copy
+/// of the state is taken every second (right before it draws the frame at
+/// `clock`). Replaying the recorded deltas from a snapshot gives back exactly
+/// the same frames.
+pub(crate) struct Timeline<S> {
+    /// `deltas[n]` moved the clock from frame `n` to frame `n + 1`.
+    deltas: Vec<Duration>,
+    snapshots: Vec<(Clock, S)>,
+    /// Furthest time reached, the end of the scrubber bar.
+    end: Duration,
+}
+
+impl<S: Clone> Timeline<S> {
+    pub(crate) fn new() -> Self {
+        Self {
+            deltas: vec![],
+            snapshots: vec![],
+            end: Duration::ZERO,
+        }
+    }
+
+    pub(crate) fn end(&self) -> Duration {
+        self.end
+    }
+
+    /// The delta recorded after `frame`, if this part of the run was already played.
+    pub(crate) fn delta_after(&self, frame: u64) -> Option<Duration> {
+        self.deltas.get(frame as usize).copied()
+    }
+
+    /// Called before the state draws the frame at `clock`. If a different
+    /// delta was recorded for this frame (the run was rewound and played
+    /// differently), the old future is forgotten.
+    pub(crate) fn record(&mut self, clock: &Clock, state: &S) {
+        self.end = self.end.max(clock.elapsed());
+
+        if let Some(previous_frame) = clock.frame().checked_sub(1) {
+            if self.delta_after(previous_frame) != Some(clock.delta()) {
+                self.deltas.truncate(previous_frame as usize);
+                self.deltas.push(clock.delta());
+                self.snapshots
+                    .retain(|(snapshot_clock, _)| snapshot_clock.frame() < clock.frame());
+            }
+        }
+
+        let due = match self.snapshots.last() {
+            None => true,
+            Some((last, _)) => {
+                clock.frame() > last.frame()
+                    && clock.elapsed() >= last.elapsed() + SNAPSHOT_INTERVAL
+            }
+        };
+
+        if due {
+            self.snapshots.push((clock.clone(), state.clone()));
+        }
+    }
+
+    /// The last snapshot taken at or before `time`.
+    pub(crate) fn snapshot_at_time(&self, time: Duration) -> Option<&(Clock, S)> {
+        self.snapshots
+            .iter()
+            .rev()
+            .find(|(clock, _)| clock.elapsed() <= time)
+    }
+
+    /// The last snapshot taken at or before `frame`.
+    pub(crate) fn snapshot_at_frame(&self, frame: u64) -> Option<&(Clock, S)> {
+        self.snapshots
+            .iter()
+            .rev()
+            .find(|(clock, _)| clock.frame() <= frame)
+    }
+}
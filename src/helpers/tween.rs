@@ -0,0 +1,268 @@
+use std::f32::consts::PI;
+use std::fmt;
+use std::time::Duration;
+
+use speedy2d::color::Color;
+use speedy2d::dimen::Vector2;
+
+use super::clock::Clock;
+use super::easing::{self, linear, Easing};
+
+/// Values which can be animated from one to another.
+pub(crate) trait Lerp: Clone {
+    /// `self` at 0, `to` at 1, and beyond them outside of 0..1.
+    fn lerp(&self, to: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Lerp for Vector2<f32> {
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        *self + (*to - *self) * t
+    }
+}
+
+impl Lerp for Color {
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        Color::from_rgba(
+            self.r().lerp(&to.r(), t),
+            self.g().lerp(&to.g(), t),
+            self.b().lerp(&to.b(), t),
+            self.a().lerp(&to.a(), t),
+        )
+    }
+}
+
+/// An angle in radians, which turns the shortest way around. A plain `f32`
+/// goes through every angle between the two values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Rotation(pub(crate) f32);
+
+impl Lerp for Rotation {
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        let turn = (to.0 - self.0 + PI).rem_euclid(2.0 * PI) - PI;
+
+        Rotation(self.0 + turn * t)
+    }
+}
+
+/// Animates a value from `from` to `to` in `duration`.
+#[derive(Clone)]
+pub(crate) struct Tween<T> {
+    pub(crate) from: T,
+    pub(crate) to: T,
+    pub(crate) duration: Duration,
+    pub(crate) easing: Easing,
+}
+
+impl<T: Lerp> Tween<T> {
+    pub(crate) fn new(from: T, to: T, duration: Duration, easing: Easing) -> Self {
+        Tween {
+            from,
+            to,
+            duration,
+            easing,
+        }
+    }
+
+    /// The value `elapsed` after the start, which stays on `to` once done.
+    pub(crate) fn at(&self, elapsed: Duration) -> T {
+        let progress = if self.duration.is_zero() {
+            1.0
+        } else {
+            (elapsed.as_secs_f32() / self.duration.as_secs_f32()).clamp(0.0, 1.0)
+        };
+
+        self.from.lerp(&self.to, (self.easing)(progress))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Tween<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tween")
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("duration", &self.duration)
+            .field("easing", &easing::name(self.easing))
+            .finish()
+    }
+}
+
+/// Tweens played one after the other, each one starting where the previous
+/// one ended. States keep when it started and ask for the value at each frame,
+/// which keeps it replayable.
+#[derive(Clone, Debug)]
+pub(crate) struct Sequence<T> {
+    start: T,
+    tweens: Vec<Tween<T>>,
+}
+
+impl<T: Lerp> Sequence<T> {
+    pub(crate) fn new(start: T) -> Self {
+        Sequence {
+            start,
+            tweens: vec![],
+        }
+    }
+
+    /// Goes to `to` after the previous tweens.
+    pub(crate) fn then(mut self, to: T, duration: Duration, easing: Easing) -> Self {
+        let from = self.end().clone();
+        self.tweens.push(Tween::new(from, to, duration, easing));
+        self
+    }
+
+    /// Stays still for `duration`.
+    pub(crate) fn wait(self, duration: Duration) -> Self {
+        let end = self.end().clone();
+        self.then(end, duration, linear)
+    }
+
+    /// Where the sequence ends.
+    pub(crate) fn end(&self) -> &T {
+        match self.tweens.last() {
+            Some(tween) => &tween.to,
+            None => &self.start,
+        }
+    }
+
+    pub(crate) fn duration(&self) -> Duration {
+        self.tweens.iter().map(|tween| tween.duration).sum()
+    }
+
+    pub(crate) fn is_finished(&self, elapsed: Duration) -> bool {
+        elapsed >= self.duration()
+    }
+
+    pub(crate) fn at(&self, mut elapsed: Duration) -> T {
+        for tween in &self.tweens {
+            if elapsed < tween.duration {
+                return tween.at(elapsed);
+            }
+            elapsed -= tween.duration;
+        }
+
+        self.end().clone()
+    }
+}
+
+/// A value which moves to its targets on its own, following the clock. The
+/// value at any time only depends on the animations started, so states holding
+/// it can be replayed.
+#[derive(Clone, Debug)]
+pub(crate) struct Animated<T> {
+    sequence: Sequence<T>,
+    start: Duration,
+}
+
+impl<T: Lerp> Animated<T> {
+    /// Stays on `value` until animated.
+    pub(crate) fn new(value: T) -> Self {
+        Animated {
+            sequence: Sequence::new(value),
+            start: Duration::ZERO,
+        }
+    }
+
+    /// Goes from wherever it is at `start` to `target`, replacing the
+    /// current animation. `start` can be in the past, e.g. the start of a
+    /// `pacing::Step`.
+    pub(crate) fn animate(
+        &mut self,
+        start: Duration,
+        target: T,
+        duration: Duration,
+        easing: Easing,
+    ) {
+        let sequence = Sequence::new(self.at(start)).then(target, duration, easing);
+        self.play(start, sequence);
+    }
+
+    /// Plays `sequence` from `start`, replacing the current animation. It
+    /// starts from its own first value, not from the current one.
+    pub(crate) fn play(&mut self, start: Duration, sequence: Sequence<T>) {
+        self.sequence = sequence;
+        self.start = start;
+    }
+
+    /// Jumps to `value`, stopping the current animation.
+    pub(crate) fn set(&mut self, value: T) {
+        *self = Animated::new(value);
+    }
+
+    pub(crate) fn at(&self, time: Duration) -> T {
+        self.sequence.at(time.saturating_sub(self.start))
+    }
+
+    pub(crate) fn value(&self, clock: &Clock) -> T {
+        self.at(clock.elapsed())
+    }
+
+    /// Whether the current animation is over, the value stays on its target.
+    pub(crate) fn is_finished(&self, clock: &Clock) -> bool {
+        self.sequence
+            .is_finished(clock.elapsed().saturating_sub(self.start))
+    }
+
+    /// Where the current animation ends.
+    pub(crate) fn target(&self) -> &T {
+        self.sequence.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(value: f32, expected: f32) {
+        assert!((value - expected).abs() < 1e-4, "{value} != {expected}");
+    }
+
+    #[test]
+    fn rotations_turn_the_shortest_way() {
+        let from = Rotation(350_f32.to_radians());
+        let to = Rotation(10_f32.to_radians());
+
+        assert_close(from.lerp(&to, 0.5).0, 360_f32.to_radians());
+        assert_close(from.lerp(&to, 1.0).0, 370_f32.to_radians());
+        assert_close(to.lerp(&from, 1.0).0, (-10_f32).to_radians());
+    }
+
+    #[test]
+    fn sequences_play_their_steps_in_order() {
+        let second = Duration::from_secs(1);
+        let sequence = Sequence::new(0.0)
+            .then(10.0, second, linear)
+            .wait(second)
+            .then(20.0, 2 * second, linear);
+
+        assert_close(sequence.at(Duration::ZERO), 0.0);
+        assert_close(sequence.at(second / 2), 5.0);
+        assert_close(sequence.at(second), 10.0);
+        assert_close(sequence.at(second * 3 / 2), 10.0);
+        assert_close(sequence.at(2 * second), 10.0);
+        assert_close(sequence.at(3 * second), 15.0);
+        assert_close(sequence.at(4 * second), 20.0);
+        assert_close(sequence.at(10 * second), 20.0);
+    }
+
+    #[test]
+    fn animated_values_finish_with_their_sequence() {
+        let second = Duration::from_secs(1);
+        let mut value = Animated::new(0.0);
+        value.play(
+            second,
+            Sequence::new(0.0).then(1.0, second, linear).wait(second),
+        );
+
+        let mut clock = Clock::new();
+        for (elapsed, finished) in [(0, false), (2, false), (3, true)] {
+            clock.tick(Duration::from_secs(elapsed) - clock.elapsed());
+            assert_eq!(value.is_finished(&clock), finished, "after {elapsed}s");
+        }
+    }
+}
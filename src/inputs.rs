@@ -0,0 +1,113 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Personal inputs (`DD.txt`) are not committed, only the examples
+/// (`DD.example.txt`, or `DD_P.example.txt` when the parts differ).
+const DIRECTORY: &str = "inputs";
+
+#[derive(Debug, Clone, Default)]
+pub(crate) enum Source {
+    /// `inputs/DD.txt`
+    #[default]
+    Personal,
+    /// The example of the puzzle text.
+    Example,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Takes `--example` and `--input <path>` out of `args`, `-` as the path
+    /// reads stdin.
+    pub(crate) fn from_args(args: &mut Vec<String>) -> Self {
+        let mut source = Source::Personal;
+
+        while let Some(index) = args
+            .iter()
+            .position(|arg| arg == "--example" || arg == "--input")
+        {
+            let arg = args.remove(index);
+            source = if arg == "--example" {
+                Source::Example
+            } else if index < args.len() {
+                match args.remove(index).as_str() {
+                    "-" => Source::Stdin,
+                    path => Source::File(path.into()),
+                }
+            } else {
+                panic!("--input <path, or - for stdin>")
+            };
+        }
+
+        source
+    }
+
+    /// Line endings are normalized, parsers only have to split on `\n`.
+    pub(crate) fn read(&self, day: u8, part: u8) -> String {
+        let input = match self {
+            Source::Personal => read_file(&personal_path(day)),
+            Source::Example => read_file(&example_path(day, part)),
+            Source::File(path) => read_file(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .expect("Cannot read the input from stdin");
+                input
+            }
+        };
+
+        input.replace("\r\n", "\n")
+    }
+}
+
+pub(crate) fn personal_path(day: u8) -> PathBuf {
+    Path::new(DIRECTORY).join(format!("{day:02}.txt"))
+}
+
+/// The example of the part if it has its own, the example of the day otherwise.
+pub(crate) fn example_path(day: u8, part: u8) -> PathBuf {
+    let path = Path::new(DIRECTORY).join(format!("{day:02}_{part}.example.txt"));
+
+    if path.exists() {
+        path
+    } else {
+        Path::new(DIRECTORY).join(format!("{day:02}.example.txt"))
+    }
+}
+
+fn read_file(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|error| {
+        panic!(
+            "Cannot read {}: {error}. Puzzle inputs are not committed, save yours there or use --input <path>.",
+            path.display()
+        )
+    })
+}
+
+/// The answer already known for the input, from `inputs/answers.txt` for
+/// personal inputs and `inputs/answers.example.txt` for examples. Both have
+/// one `DD P answer` line per part, the first one is not committed either.
+pub(crate) fn expected_answer(source: &Source, day: u8, part: u8) -> Option<String> {
+    let answers = match source {
+        Source::Personal => "answers.txt",
+        Source::Example => "answers.example.txt",
+        Source::File(_) | Source::Stdin => return None,
+    };
+
+    fs::read_to_string(Path::new(DIRECTORY).join(answers))
+        .ok()?
+        .lines()
+        .filter_map(|line| {
+            let mut values = line.split_whitespace();
+
+            Some((
+                values.next()?.parse::<u8>().ok()?,
+                values.next()?.parse::<u8>().ok()?,
+                values.next()?.to_string(),
+            ))
+        })
+        .find(|(answer_day, answer_part, _)| (*answer_day, *answer_part) == (day, part))
+        .map(|(_, _, answer)| answer)
+}
@@ -0,0 +1,218 @@
+use std::env;
+use std::process;
+
+use helpers::RunOptions;
+use inputs::Source;
+use solutions::day01::Day01;
+use solutions::day02::Day02;
+use solutions::day03::Day03;
+use solutions::day04::Day04;
+use solutions::day05::Day05;
+use solutions::day06::Day06;
+use solutions::day07::Day07;
+use solutions::Solution;
+
+mod helpers;
+mod inputs;
+mod solutions;
+mod visualizations;
+
+const USAGE: &str = "Usage:
+    aoc list
+    aoc solve <day> [<part>] [--example | --input <path>]
+    aoc viz <day> <part> [--example | --input <path>]
+                         [--export <dir> | --gif <file> | --apng <file>]
+                         [--fps <fps>] [--scale <factor>] [--palette-speed <1 to 30>]
+                         [--max-seconds <seconds>]
+                         [--placement <none | bspc | shell command>]
+                         [--layout <portrait | landscape | <width>x<height>>]
+                         [--preview] [--text-cache <megabytes>]
+
+The input is read from inputs/DD.txt by default, --input - reads it from stdin.
+The window placement defaults to $AOC_WINDOW_PLACEMENT, or none.
+Characters missing from Source Code Pro are drawn with the fonts in $AOC_FALLBACK_FONTS
+(separated by :), then DejaVu Sans and Noto symbols and emoji when installed.
+The layout defaults to portrait (810x1440), landscape is 1440x810.
+--preview opens the window at half size, it can be resized and F toggles fullscreen.
+Exports stop once the final screen has been shown, or after --max-seconds (10 minutes by default).
+--text-cache bounds the memory kept by the texts (64 MB by default), C shows its use.";
+
+type Visualize = fn(&str, RunOptions);
+
+struct Day {
+    number: u8,
+    title: &'static str,
+    /// Parses the input and returns the answer of the given part.
+    solve: fn(&str, u8) -> String,
+    /// Part 1 and part 2, not every part has one.
+    visualizations: [Option<Visualize>; 2],
+}
+
+/// Every day, in order. A new day only has to be added here.
+const DAYS: [Day; 7] = [
+    Day {
+        number: 1,
+        title: "Trebuchet?!",
+        solve: solve::<Day01>,
+        visualizations: [
+            Some(visualizations::day01_1::visualize),
+            Some(visualizations::day01_2::visualize),
+        ],
+    },
+    Day {
+        number: 2,
+        title: "Cube Conundrum",
+        solve: solve::<Day02>,
+        visualizations: [
+            Some(visualizations::day02_1::visualize),
+            Some(visualizations::day02_2::visualize),
+        ],
+    },
+    Day {
+        number: 3,
+        title: "Gear Ratios",
+        solve: solve::<Day03>,
+        visualizations: [
+            Some(visualizations::day03_1::visualize),
+            Some(visualizations::day03_2::visualize),
+        ],
+    },
+    Day {
+        number: 4,
+        title: "Scratchcards",
+        solve: solve::<Day04>,
+        visualizations: [
+            Some(visualizations::day04_1::visualize),
+            Some(visualizations::day04_2::visualize),
+        ],
+    },
+    Day {
+        number: 5,
+        title: "If You Give A Seed A Fertilizer",
+        solve: solve::<Day05>,
+        visualizations: [
+            Some(visualizations::day05_1::visualize),
+            Some(visualizations::day05_2::visualize),
+        ],
+    },
+    Day {
+        number: 6,
+        title: "Wait For It",
+        solve: solve::<Day06>,
+        visualizations: [
+            Some(visualizations::day06_1::visualize),
+            Some(visualizations::day06_2::visualize),
+        ],
+    },
+    Day {
+        number: 7,
+        title: "Camel Cards",
+        solve: solve::<Day07>,
+        visualizations: [None, None],
+    },
+];
+
+fn solve<S: Solution>(input: &str, part: u8) -> String {
+    let input = S::parse(input);
+
+    match part {
+        1 => S::part1(&input).to_string(),
+        2 => S::part2(&input).to_string(),
+        _ => unreachable!(),
+    }
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+
+    match args.next().as_deref() {
+        Some("list") => list(),
+        Some("solve") => {
+            let mut args: Vec<_> = args.collect();
+            let source = Source::from_args(&mut args);
+            let mut args = args.into_iter();
+            let day = day_from_arg(args.next());
+            let parts = match args.next() {
+                Some(part) => vec![part_from_arg(Some(part))],
+                None => vec![1, 2],
+            };
+
+            // Read once, stdin can only be read once. Only examples can
+            // differ from one part to the other.
+            let mut input = source.read(day.number, parts[0]);
+            for &part in &parts {
+                if part != parts[0] && matches!(source, Source::Example) {
+                    input = source.read(day.number, part);
+                }
+
+                println!(
+                    "Day {:02} - Part {part}: {}",
+                    day.number,
+                    (day.solve)(&input, part)
+                );
+            }
+        }
+        Some("viz") => {
+            let mut args: Vec<_> = args.collect();
+            let source = Source::from_args(&mut args);
+            let mut args = args.into_iter();
+            let day = day_from_arg(args.next());
+            let part = part_from_arg(args.next());
+
+            match day.visualizations[part as usize - 1] {
+                Some(visualize) => {
+                    let mut options = RunOptions::from_args(day.number, part, args);
+                    options.expected_answer = inputs::expected_answer(&source, day.number, part);
+
+                    visualize(&source.read(day.number, part), options)
+                }
+                None => {
+                    eprintln!("Day {:02} part {part} has no visualization.", day.number);
+                    process::exit(1);
+                }
+            }
+        }
+        _ => usage(),
+    }
+}
+
+fn list() {
+    for day in &DAYS {
+        let visualizations: Vec<_> = day
+            .visualizations
+            .iter()
+            .enumerate()
+            .filter(|(_, visualize)| visualize.is_some())
+            .map(|(index, _)| (index + 1).to_string())
+            .collect();
+
+        println!(
+            "{:02}  {:<32} viz: {}",
+            day.number,
+            day.title,
+            if visualizations.is_empty() {
+                "-".to_string()
+            } else {
+                visualizations.join(", ")
+            }
+        );
+    }
+}
+
+/// Accepts `5` as well as `05`.
+fn day_from_arg(arg: Option<String>) -> &'static Day {
+    arg.and_then(|arg| arg.parse::<u8>().ok())
+        .and_then(|number| DAYS.iter().find(|day| day.number == number))
+        .unwrap_or_else(|| usage())
+}
+
+fn part_from_arg(arg: Option<String>) -> u8 {
+    arg.and_then(|arg| arg.parse().ok())
+        .filter(|part| (1..=2).contains(part))
+        .unwrap_or_else(|| usage())
+}
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    process::exit(2);
+}
@@ -0,0 +1,101 @@
+use std::fmt::Display;
+
+pub(crate) mod day01;
+pub(crate) mod day02;
+pub(crate) mod day03;
+pub(crate) mod day04;
+pub(crate) mod day05;
+pub(crate) mod day06;
+pub(crate) mod day07;
+
+/// Solves a day without drawing anything. Visualizations start from the
+/// parsed input instead of parsing it again.
+pub(crate) trait Solution {
+    /// The puzzle input once parsed, shared by both parts.
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Self::Input;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Personal inputs and their answers are local files: without them only the
+/// examples are checked.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inputs::{self, Source};
+
+    fn check<S: Solution>(day: u8) {
+        for source in [Source::Example, Source::Personal] {
+            if matches!(source, Source::Personal) && !inputs::personal_path(day).exists() {
+                continue;
+            }
+
+            for part in [1, 2] {
+                let Some(expected) = inputs::expected_answer(&source, day, part) else {
+                    continue;
+                };
+                let input = S::parse(&source.read(day, part));
+                let answer = match part {
+                    1 => S::part1(&input).to_string(),
+                    _ => S::part2(&input).to_string(),
+                };
+
+                assert_eq!(answer, expected, "day {day:02} part {part} ({source:?})");
+            }
+        }
+    }
+
+    #[test]
+    fn day01() {
+        check::<day01::Day01>(1);
+    }
+
+    #[test]
+    fn day02() {
+        check::<day02::Day02>(2);
+    }
+
+    #[test]
+    fn day03() {
+        check::<day03::Day03>(3);
+    }
+
+    #[test]
+    fn day04() {
+        check::<day04::Day04>(4);
+    }
+
+    #[test]
+    fn day05() {
+        check::<day05::Day05>(5);
+    }
+
+    #[test]
+    fn day06() {
+        check::<day06::Day06>(6);
+    }
+
+    #[test]
+    fn day07() {
+        check::<day07::Day07>(7);
+    }
+
+    /// The examples have to cover every part, or the tests pass on nothing.
+    #[test]
+    fn every_example_has_an_answer() {
+        for day in 1..=7 {
+            for part in [1, 2] {
+                assert!(
+                    inputs::expected_answer(&Source::Example, day, part).is_some(),
+                    "day {day:02} part {part}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use super::Solution;
+
+const AS_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub(crate) struct Day01;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub(crate) struct Number {
+    pub(crate) position: usize,
+    pub(crate) len: usize,
+    pub(crate) value: u32,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Line {
+    pub(crate) text: String,
+    pub(crate) chars: Vec<char>,
+    /// Only the digits (part 1).
+    pub(crate) digits: Vec<Number>,
+    /// The digits and the numbers spelled out with letters (part 2).
+    pub(crate) numbers: Vec<Number>,
+}
+
+impl Line {
+    fn new(text: &str) -> Self {
+        let chars: Vec<char> = text.chars().collect();
+        let mut digits = vec![];
+        let mut numbers = vec![];
+
+        for (char_index, char) in chars.iter().enumerate() {
+            for (index_of_word, number_as_word) in AS_WORDS.iter().enumerate() {
+                if char_index + number_as_word.len() > chars.len() {
+                    continue;
+                }
+
+                if chars[char_index..char_index + number_as_word.len()].to_vec()
+                    == number_as_word.chars().collect::<Vec<char>>()
+                {
+                    numbers.push(Number {
+                        position: char_index,
+                        len: number_as_word.len(),
+                        value: index_of_word as u32 + 1,
+                    });
+                }
+            }
+
+            if let Ok(value) = char.to_string().parse::<u32>() {
+                let number = Number {
+                    position: char_index,
+                    len: 1,
+                    value,
+                };
+                digits.push(number);
+                numbers.push(number);
+            }
+        }
+
+        Self {
+            text: text.to_string(),
+            chars,
+            digits,
+            numbers,
+        }
+    }
+}
+
+/// The first and the last number of the line, as a two digits number.
+pub(crate) fn calibration_value(numbers: &[Number]) -> u32 {
+    numbers[0].value * 10 + numbers[numbers.len() - 1].value
+}
+
+impl Solution for Day01 {
+    type Input = Vec<Line>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .map(|line| Line::new(line.trim()))
+            .collect()
+    }
+
+    fn part1(lines: &Self::Input) -> u32 {
+        lines
+            .iter()
+            .map(|line| calibration_value(&line.digits))
+            .sum()
+    }
+
+    fn part2(lines: &Self::Input) -> u32 {
+        lines
+            .iter()
+            .map(|line| calibration_value(&line.numbers))
+            .sum()
+    }
+}
@@ -0,0 +1,104 @@
+use super::Solution;
+
+/// The cubes loaded in the bag (part 1).
+pub(crate) const BAG: Set = Set {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+pub(crate) struct Day02;
+
+#[derive(Default, Clone, Copy, Debug)]
+pub(crate) struct Set {
+    pub(crate) blue: u32,
+    pub(crate) green: u32,
+    pub(crate) red: u32,
+}
+
+impl Set {
+    pub(crate) fn fits_in(&self, bag: &Set) -> bool {
+        self.red <= bag.red && self.green <= bag.green && self.blue <= bag.blue
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Color {
+    /// Of the border and the face of the cubes in the visualizations.
+    pub(crate) fn hex_rgb(self) -> (u32, u32) {
+        match self {
+            Color::Red => (0xef4444, 0xdc2626),
+            Color::Green => (0x10b981, 0x059669),
+            Color::Blue => (0x06b6d4, 0x0891b2),
+        }
+    }
+}
+
+/// The fewest cubes of each color which make every set of the game possible.
+pub(crate) fn minimum_bag(game: &[Set]) -> Set {
+    Set {
+        blue: game.iter().map(|set| set.blue).max().unwrap_or(0),
+        green: game.iter().map(|set| set.green).max().unwrap_or(0),
+        red: game.iter().map(|set| set.red).max().unwrap_or(0),
+    }
+}
+
+impl Solution for Day02 {
+    /// The sets of every game, game n°1 first.
+    type Input = Vec<Vec<Set>>;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .map(|line| line.trim().split_once(": ").unwrap().1)
+            .map(|line| {
+                line.split("; ")
+                    .map(|set_as_string| {
+                        let mut set = Set::default();
+                        set_as_string.split(", ").for_each(|cubes| {
+                            if cubes.ends_with(" blue") {
+                                set.blue = cubes.trim_end_matches(" blue").parse().unwrap()
+                            } else if cubes.ends_with(" red") {
+                                set.red = cubes.trim_end_matches(" red").parse().unwrap()
+                            } else if cubes.ends_with(" green") {
+                                set.green = cubes.trim_end_matches(" green").parse().unwrap()
+                            } else {
+                                panic!();
+                            }
+                        });
+
+                        set
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(games: &Self::Input) -> usize {
+        games
+            .iter()
+            .enumerate()
+            .filter(|(_, game)| game.iter().all(|set| set.fits_in(&BAG)))
+            .map(|(index, _)| index + 1)
+            .sum()
+    }
+
+    fn part2(games: &Self::Input) -> u32 {
+        games
+            .iter()
+            .map(|game| {
+                let bag = minimum_bag(game);
+                bag.red * bag.green * bag.blue
+            })
+            .sum()
+    }
+}
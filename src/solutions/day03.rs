@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use super::Solution;
+
+pub(crate) struct Day03;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Number {
+    pub(crate) string: String,
+    pub(crate) value: u32,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Spot {
+    Symbol(String),
+    /// Stored at the position of its first digit.
+    Number(Number),
+}
+
+/// Every `*` with the position and value of the numbers touching it.
+pub(crate) type Gears = HashMap<(i32, i32), Vec<((i32, i32), u32)>>;
+
+/// Positions are `(column_index, line_index)`.
+#[derive(Debug, Clone)]
+pub(crate) struct Schematic {
+    pub(crate) map: HashMap<(i32, i32), Spot>,
+    pub(crate) last_line_index: i32,
+    pub(crate) last_column_index: i32,
+}
+
+impl Schematic {
+    /// Every position touching the number at `(column_index, line_index)`,
+    /// diagonals included.
+    fn around_number(
+        (column_index, line_index): (i32, i32),
+        number: &Number,
+    ) -> impl Iterator<Item = (i32, i32)> {
+        let end_column_index = column_index + number.string.len() as i32;
+
+        (column_index - 1..end_column_index + 1)
+            .flat_map(move |column_index| {
+                [
+                    (column_index, line_index - 1),
+                    (column_index, line_index + 1),
+                ]
+            })
+            .chain([
+                (column_index - 1, line_index),
+                (end_column_index, line_index),
+            ])
+    }
+
+    pub(crate) fn numbers(&self) -> impl Iterator<Item = ((i32, i32), &Number)> {
+        self.map.iter().filter_map(|(position, spot)| match spot {
+            Spot::Number(number) => Some((*position, number)),
+            Spot::Symbol(_) => None,
+        })
+    }
+
+    pub(crate) fn has_symbol(&self, position: (i32, i32), number: &Number) -> bool {
+        Self::around_number(position, number)
+            .any(|position| matches!(self.map.get(&position), Some(Spot::Symbol(_))))
+    }
+
+    /// Only the ones with exactly two numbers are real gears.
+    pub(crate) fn gears(&self) -> Gears {
+        let mut gears: Gears = self
+            .map
+            .iter()
+            .filter(|(_, spot)| matches!(spot, Spot::Symbol(symbol) if symbol == "*"))
+            .map(|(position, _)| (*position, vec![]))
+            .collect();
+
+        for (number_position, number) in self.numbers() {
+            for position in Self::around_number(number_position, number) {
+                if let Some(values) = gears.get_mut(&position) {
+                    values.push((number_position, number.value));
+                }
+            }
+        }
+
+        gears
+    }
+}
+
+impl Solution for Day03 {
+    type Input = Schematic;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        let map = input
+            .trim()
+            .lines()
+            .enumerate()
+            .flat_map(|(line_index, line)| {
+                let line = line.trim();
+                let mut values = vec![];
+
+                let mut column_index = 0;
+                let mut width = 1;
+                let mut current_number = None;
+                while let Some(element) = line.get(column_index..column_index + width) {
+                    if element == "." {
+                        column_index += 1;
+                        continue;
+                    }
+
+                    if let Ok(number) = element.parse::<u32>() {
+                        current_number = Some(number);
+                        width += 1;
+                    } else if let Some(number) = current_number {
+                        values.push((
+                            (column_index as i32, line_index as i32),
+                            Spot::Number(Number {
+                                string: line
+                                    .get(column_index..column_index + width - 1)
+                                    .unwrap()
+                                    .to_string(),
+                                value: number,
+                            }),
+                        ));
+
+                        current_number = None;
+                        column_index += width - 1;
+                        width = 1;
+                    } else {
+                        values.push((
+                            (column_index as i32, line_index as i32),
+                            Spot::Symbol(element.to_string()),
+                        ));
+                        column_index += 1;
+                    }
+                }
+
+                if let Some(number) = current_number {
+                    values.push((
+                        (column_index as i32, line_index as i32),
+                        Spot::Number(Number {
+                            string: line
+                                .get(column_index..column_index + width - 1)
+                                .unwrap()
+                                .to_string(),
+                            value: number,
+                        }),
+                    ));
+                }
+
+                values.into_iter()
+            })
+            .collect();
+
+        Schematic {
+            map,
+            last_line_index: input.trim().lines().count() as i32 - 1,
+            last_column_index: input.trim().lines().next().unwrap().trim().len() as i32 - 1,
+        }
+    }
+
+    fn part1(schematic: &Self::Input) -> u32 {
+        schematic
+            .numbers()
+            .filter(|(position, number)| schematic.has_symbol(*position, number))
+            .map(|(_, number)| number.value)
+            .sum()
+    }
+
+    fn part2(schematic: &Self::Input) -> u32 {
+        schematic
+            .gears()
+            .values()
+            .filter(|values| values.len() == 2)
+            .map(|values| values[0].1 * values[1].1)
+            .sum()
+    }
+}
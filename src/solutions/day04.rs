@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+
+use super::Solution;
+
+pub(crate) struct Day04;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Scratchcard {
+    pub(crate) digits: Vec<u32>,
+    pub(crate) winning_digits: HashSet<u32>,
+}
+
+impl Scratchcard {
+    pub(crate) fn number_of_winning_digits(&self) -> u32 {
+        self.digits
+            .iter()
+            .filter(|digit| self.winning_digits.contains(*digit))
+            .count() as u32
+    }
+
+    /// One point for the first winning digit, doubled for each of the next ones.
+    pub(crate) fn score(&self) -> u32 {
+        match self.number_of_winning_digits() {
+            0 => 0,
+            number_of_winning_digits => 2_u32.pow(number_of_winning_digits - 1),
+        }
+    }
+}
+
+impl Solution for Day04 {
+    type Input = Vec<Scratchcard>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (_prefix, numbers_lists) = line.trim().split_once(": ").unwrap();
+                let (digits, winning_digits) = numbers_lists.split_once(" | ").unwrap();
+
+                let digits: Vec<_> = digits
+                    .split_whitespace()
+                    .map(str::parse)
+                    .map(Result::unwrap)
+                    .collect();
+                let winning_digits: HashSet<_> = winning_digits
+                    .split_whitespace()
+                    .map(str::parse)
+                    .map(Result::unwrap)
+                    .collect();
+
+                Scratchcard {
+                    digits,
+                    winning_digits,
+                }
+            })
+            .collect()
+    }
+
+    fn part1(cards: &Self::Input) -> u32 {
+        cards.iter().map(Scratchcard::score).sum()
+    }
+
+    /// Each card wins one copy of the next `number_of_winning_digits` cards,
+    /// for every copy of itself.
+    fn part2(cards: &Self::Input) -> u64 {
+        let mut quantities = vec![1_u64; cards.len()];
+
+        for (index, card) in cards.iter().enumerate() {
+            let number_of_winning_digits = card.number_of_winning_digits() as usize;
+
+            for next_index in index + 1..=index + number_of_winning_digits {
+                if next_index >= cards.len() {
+                    break;
+                }
+
+                quantities[next_index] += quantities[index];
+            }
+        }
+
+        quantities.iter().sum()
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+use std::ops;
+
+use itertools::Itertools;
+
+use super::Solution;
+
+pub(crate) struct Day05;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Almanac {
+    pub(crate) seeds: Vec<i64>,
+    /// Indexed by the category the section maps from.
+    pub(crate) maps: HashMap<String, Section>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Section {
+    pub(crate) from: String,
+    pub(crate) to: String,
+    pub(crate) ranges: Vec<Range>,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Range {
+    pub(crate) destination_start: i64,
+    pub(crate) source_start: i64,
+    pub(crate) length: i64,
+}
+
+impl Range {
+    fn source(&self) -> ops::Range<i64> {
+        self.source_start..self.source_start + self.length
+    }
+
+    fn diff(&self) -> i64 {
+        self.destination_start - self.source_start
+    }
+}
+
+pub(crate) fn part_index_to_string(index: usize) -> &'static str {
+    match index {
+        0 => "seed",
+        1 => "soil",
+        2 => "fertilizer",
+        3 => "water",
+        4 => "light",
+        5 => "temperature",
+        6 => "humidity",
+        7 => "location",
+        _ => panic!(),
+    }
+}
+
+/// The color of each category in the visualizations, from its image.
+pub(crate) fn category_color(index: usize) -> u32 {
+    match index {
+        0 => 0x86efac,
+        1 => 0xf87171,
+        2 => 0xd6a27a,
+        3 => 0x60a5fa,
+        4 => 0xfacc15,
+        5 => 0xef4444,
+        6 => 0x38bdf8,
+        7 => 0x2dd4bf,
+        _ => panic!("There are only 8 categories, no category {index}"),
+    }
+}
+
+impl Almanac {
+    /// The value of the seed in each of the 8 categories, from seed to location.
+    pub(crate) fn seed_path(&self, seed: i64) -> Vec<i64> {
+        let mut path = vec![seed];
+
+        'seed_loop: while path.len() < 8 {
+            let index = path.len();
+            let value = *path.last().unwrap();
+            for range in &self
+                .maps
+                .get(part_index_to_string(index - 1))
+                .unwrap()
+                .ranges
+            {
+                if value >= range.source_start && value < range.source_start + range.length {
+                    path.push(value + range.diff());
+                    continue 'seed_loop;
+                }
+            }
+
+            path.push(value);
+        }
+
+        path
+    }
+
+    /// In part 2, the seeds line is made of pairs of start and length.
+    pub(crate) fn seed_ranges(&self) -> Vec<ops::Range<i64>> {
+        self.seeds
+            .iter()
+            .tuples()
+            .map(|(from, length)| *from..from + length)
+            .collect()
+    }
+
+    /// The ranges in each of the 8 categories, from seed to location. A range
+    /// is split each time only a part of it is moved by a section.
+    pub(crate) fn ranges_path(&self, ranges: Vec<ops::Range<i64>>) -> Vec<Vec<ops::Range<i64>>> {
+        let mut values = vec![ranges];
+
+        while let Some(map) = self.maps.get(part_index_to_string(values.len() - 1)) {
+            let mut ranges_todo = values.last().unwrap().clone();
+            let mut new_ranges = vec![];
+
+            'main_loop: while let Some(range) = ranges_todo.pop() {
+                for diff_info in &map.ranges {
+                    let diff_range = diff_info.source();
+
+                    if range.start >= diff_range.end || range.end < diff_range.start {
+                        continue;
+                    }
+
+                    if diff_range.contains(&range.start) && diff_range.contains(&(range.end - 1)) {
+                        new_ranges
+                            .push(range.start + diff_info.diff()..range.end + diff_info.diff());
+                        continue 'main_loop;
+                    }
+
+                    if range.start <= diff_range.start && range.end >= diff_range.end {
+                        ranges_todo.push(range.start..diff_range.start);
+                        ranges_todo.push(diff_range.clone());
+                        ranges_todo.push(diff_range.end..range.end);
+                        continue 'main_loop;
+                    }
+
+                    if diff_range.contains(&range.start) {
+                        ranges_todo.push(range.start..diff_range.end);
+                        ranges_todo.push(diff_range.end..range.end);
+                        continue 'main_loop;
+                    }
+
+                    if diff_range.contains(&(range.end - 1)) {
+                        ranges_todo.push(range.start..diff_range.start);
+                        ranges_todo.push(diff_range.start..range.end);
+                        continue 'main_loop;
+                    }
+                }
+
+                new_ranges.push(range);
+            }
+
+            values.push(new_ranges);
+        }
+
+        values
+    }
+}
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Self::Input {
+        let (seeds, maps) = input.trim().split_once("\n\n").unwrap();
+
+        let seeds = seeds
+            .trim()
+            .split_once(": ")
+            .unwrap()
+            .1
+            .split_whitespace()
+            .map(|seed| seed.parse().unwrap())
+            .collect();
+
+        let maps = maps
+            .split("\n\n")
+            .map(|section| {
+                let (header, ranges) = section.split_once('\n').unwrap();
+                let mut header = header.trim().trim_end_matches(" map:").split('-');
+                let from = header.next().unwrap().to_string();
+                header.next().unwrap();
+                let to = header.next().unwrap().to_string();
+
+                let ranges = ranges
+                    .lines()
+                    .map(|line| {
+                        let mut numbers = line.split_whitespace();
+
+                        Range {
+                            destination_start: numbers.next().unwrap().parse().unwrap(),
+                            source_start: numbers.next().unwrap().parse().unwrap(),
+                            length: numbers.next().unwrap().parse().unwrap(),
+                        }
+                    })
+                    .collect();
+
+                (from.clone(), Section { from, to, ranges })
+            })
+            .collect();
+
+        Almanac { seeds, maps }
+    }
+
+    fn part1(almanac: &Self::Input) -> i64 {
+        almanac
+            .seeds
+            .iter()
+            .map(|seed| *almanac.seed_path(*seed).last().unwrap())
+            .min()
+            .unwrap()
+    }
+
+    fn part2(almanac: &Self::Input) -> i64 {
+        almanac
+            .ranges_path(almanac.seed_ranges())
+            .last()
+            .unwrap()
+            .iter()
+            .map(|range| range.start)
+            .min()
+            .unwrap()
+    }
+}
@@ -0,0 +1,75 @@
+use super::Solution;
+
+pub(crate) struct Day06;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Race {
+    pub(crate) time: u64,
+    pub(crate) distance: u64,
+}
+
+impl Race {
+    /// Number of ways to hold the button which go farther than the record.
+    pub(crate) fn winning_moves(&self) -> u64 {
+        let mut winning_moves = 0;
+        for x in 1..self.time {
+            let distance = (self.time - x) * x;
+            if distance > self.distance {
+                winning_moves += 1;
+            }
+        }
+
+        winning_moves
+    }
+}
+
+/// Part 2 ignores the spaces between the numbers: there is only one race.
+fn single_race(races: &[Race]) -> Race {
+    let concat = |values: Vec<u64>| {
+        values
+            .iter()
+            .map(u64::to_string)
+            .collect::<String>()
+            .parse()
+            .unwrap()
+    };
+
+    Race {
+        time: concat(races.iter().map(|race| race.time).collect()),
+        distance: concat(races.iter().map(|race| race.distance).collect()),
+    }
+}
+
+impl Solution for Day06 {
+    type Input = Vec<Race>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut lines = input.trim().lines().map(|line| {
+            line.split_once(':')
+                .unwrap()
+                .1
+                .split_whitespace()
+                .map(|value| value.parse::<u64>().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        let times = lines.next().unwrap();
+        let distances = lines.next().unwrap();
+
+        times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, distance)| Race { time, distance })
+            .collect()
+    }
+
+    fn part1(races: &Self::Input) -> u64 {
+        races.iter().map(Race::winning_moves).product()
+    }
+
+    fn part2(races: &Self::Input) -> u64 {
+        single_race(races).winning_moves()
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use super::Solution;
+
+const CARDS: &str = "23456789TJQKA";
+/// In part 2, J are jokers: the weakest card, but they count as any other card
+/// to make the best type.
+const CARDS_WITH_JOKERS: &str = "J23456789TQKA";
+const MAX: usize = 13;
+
+pub(crate) struct Day07;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Hand {
+    pub(crate) cards: String,
+    pub(crate) bid: usize,
+}
+
+impl Hand {
+    fn cards_counts(&self) -> HashMap<char, usize> {
+        let mut cards_counts: HashMap<char, usize> = HashMap::new();
+        for card in self.cards.chars() {
+            *cards_counts.entry(card).or_default() += 1;
+        }
+
+        cards_counts
+    }
+
+    /// The cards of the hand as a base 13 number, first card first.
+    fn cards_score(&self, cards_values: &str) -> usize {
+        self.cards
+            .chars()
+            .rev()
+            .enumerate()
+            .map(|(position, card)| cards_values.find(card).unwrap() * MAX.pow(position as u32))
+            .sum()
+    }
+
+    /// From 1 (high card) to 7 (five of a kind).
+    fn type_score(&self) -> usize {
+        let cards_counts = self.cards_counts();
+
+        if cards_counts.values().contains(&5) {
+            7
+        } else if cards_counts.values().contains(&4) {
+            6
+        } else if cards_counts.values().contains(&3) && cards_counts.values().contains(&2) {
+            5
+        } else if cards_counts.values().contains(&3) {
+            4
+        } else if cards_counts.values().contains(&2) && cards_counts.len() == 3 {
+            3
+        } else if cards_counts.values().contains(&2) {
+            2
+        } else {
+            1
+        }
+    }
+
+    fn type_score_with_jokers(&self) -> usize {
+        let cards_counts = self.cards_counts();
+
+        match cards_counts.get(&'J').unwrap_or(&0) {
+            4.. => 7,
+            3 => match cards_counts.len() {
+                2 => 7,
+                3 => 6,
+                _ => panic!(),
+            },
+            2 => match cards_counts.len() {
+                2 => 7,
+                3 => 6,
+                4 => 4,
+                _ => panic!(),
+            },
+            1 => match cards_counts.len() {
+                2 => 7,
+                3 if cards_counts.values().contains(&3) => 6,
+                3 => 5,
+                4 => 4,
+                5 => 2,
+                _ => panic!(),
+            },
+            _ => self.type_score(),
+        }
+    }
+
+    pub(crate) fn score(&self) -> usize {
+        self.type_score() * MAX.pow(5) + self.cards_score(CARDS)
+    }
+
+    pub(crate) fn score_with_jokers(&self) -> usize {
+        self.type_score_with_jokers() * MAX.pow(5) + self.cards_score(CARDS_WITH_JOKERS)
+    }
+}
+
+/// Each bid multiplied by the rank of its hand, the weakest hand being 1.
+fn total_winnings(hands: &[Hand], score: impl Fn(&Hand) -> usize) -> usize {
+    hands
+        .iter()
+        .sorted_by_key(|hand| score(hand))
+        .enumerate()
+        .map(|(index, hand)| hand.bid * (index + 1))
+        .sum()
+}
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (cards, bid) = line.trim().split_once(' ').unwrap();
+
+                Hand {
+                    cards: cards.to_string(),
+                    bid: bid.parse().unwrap(),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(hands: &Self::Input) -> usize {
+        total_winnings(hands, Hand::score)
+    }
+
+    fn part2(hands: &Self::Input) -> usize {
+        total_winnings(hands, Hand::score_with_jokers)
+    }
+}
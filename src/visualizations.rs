@@ -0,0 +1,12 @@
+pub(crate) mod day01_1;
+pub(crate) mod day01_2;
+pub(crate) mod day02_1;
+pub(crate) mod day02_2;
+pub(crate) mod day03_1;
+pub(crate) mod day03_2;
+pub(crate) mod day04_1;
+pub(crate) mod day04_2;
+pub(crate) mod day05_1;
+pub(crate) mod day05_2;
+pub(crate) mod day06_1;
+pub(crate) mod day06_2;
@@ -0,0 +1,181 @@
+use std::time::Duration;
+
+use crate::helpers::clock::Clock;
+use crate::helpers::pacing::{Pacing, Sequencer};
+use crate::helpers::renderer::Renderer;
+use crate::helpers::{run, Glow, RunOptions, State, TextManager, TextType};
+use crate::solutions::day01::{calibration_value, Day01, Line};
+use crate::solutions::Solution;
+
+const FONT_SIZE: u32 = 128;
+
+#[derive(Clone)]
+struct MyState {
+    lines: Vec<Line>,
+
+    /// One item per line, the cursor going through its chars.
+    sequencer: Sequencer,
+    line_index: usize,
+}
+
+impl State for MyState {
+    fn on_draw(
+        &mut self,
+        clock: &Clock,
+        text_manager: &mut TextManager,
+        renderer: &mut dyn Renderer,
+    ) {
+        let layout = renderer.layout();
+        // Distance between two lines of the input.
+        let line_spacing = layout.height() * 0.07;
+
+        let char_width = text_manager.measure("A", FONT_SIZE).advance_width;
+
+        let step = self.sequencer.at(clock.elapsed());
+        self.line_index = step.map_or(self.lines.len(), |step| step.index);
+        let sum: u32 = self.lines[0..self.line_index]
+            .iter()
+            .map(|line| calibration_value(&line.numbers))
+            .sum();
+
+        let Some(step) = step else {
+            text_manager.draw_answer(
+                renderer,
+                FONT_SIZE * 2,
+                (layout.width() / 2.0, layout.height() / 2.0),
+                sum.to_string(),
+            );
+
+            return;
+        };
+
+        let steps = cursor_steps(&self.lines[self.line_index]);
+        let cursor = ((step.progress * steps as f32) as usize).min(steps - 1);
+
+        let numbers = &self.lines[self.line_index].numbers;
+        let first = numbers[0];
+        let mut current_last = first;
+        for number in numbers {
+            if number.position > cursor {
+                break;
+            }
+            current_last = *number;
+        }
+
+        for (char_index, char) in self.lines[self.line_index].chars.iter().enumerate() {
+            let char_string = char.to_string();
+
+            let text_type = if char_index >= current_last.position
+                && char_index < (current_last.position + current_last.len)
+            {
+                TextType::Glow(Glow::GOLD)
+            } else if char_index == cursor {
+                TextType::Glow(Glow::WHITE)
+            } else {
+                TextType::Gray
+            };
+
+            let offset_width = (char_index as i32 - cursor as i32) as f32 * char_width;
+
+            text_manager.draw_text(
+                renderer,
+                FONT_SIZE,
+                text_type,
+                (layout.width() / 2.0 + offset_width, layout.height() / 2.0),
+                char_string,
+            );
+        }
+
+        for i in self.line_index + 1..self.lines.len() {
+            let offset = i - self.line_index;
+            if offset > 5 {
+                break;
+            }
+
+            text_manager.draw_text(
+                renderer,
+                FONT_SIZE / 2,
+                TextType::Gray,
+                (
+                    layout.width() / 2.0,
+                    layout.height() / 2.0 + (offset + 1) as f32 * line_spacing,
+                ),
+                self.lines[i].text.to_string(),
+            );
+        }
+
+        if cursor >= first.position {
+            text_manager.draw_text(
+                renderer,
+                FONT_SIZE,
+                TextType::Glow(Glow::GOLD),
+                (
+                    layout.width() / 2.0 - char_width / 2.0,
+                    layout.height() / 2.0 - 2.0 * line_spacing,
+                ),
+                first.value.to_string(),
+            );
+        }
+        if cursor >= current_last.position {
+            text_manager.draw_text(
+                renderer,
+                FONT_SIZE,
+                TextType::Glow(Glow::GOLD),
+                (
+                    layout.width() / 2.0 + char_width / 2.0,
+                    layout.height() / 2.0 - 2.0 * line_spacing,
+                ),
+                current_last.value.to_string(),
+            );
+        }
+
+        text_manager.draw_text(
+            renderer,
+            FONT_SIZE,
+            TextType::Glow(Glow::WHITE),
+            (
+                layout.width() / 2.0,
+                layout.height() / 2.0 - 4.0 * line_spacing,
+            ),
+            sum.to_string(),
+        );
+    }
+
+    fn is_finished(&self) -> bool {
+        self.line_index == self.lines.len()
+    }
+}
+
+pub(crate) fn visualize(input: &str, options: RunOptions) {
+    let lines = Day01::parse(input);
+
+    // Slowly for the first and the last lines, durations are per cursor step.
+    let pacing = Pacing::new(Duration::from_micros(100))
+        .first(1, Duration::from_millis(500))
+        .first(1, Duration::from_millis(250))
+        .first(1, Duration::from_millis(167))
+        .first(1, Duration::from_millis(125))
+        .first(1, Duration::from_millis(100))
+        .first(5, Duration::from_millis(1))
+        .last(5, Duration::from_millis(1))
+        .last(1, Duration::from_millis(13))
+        .last(1, Duration::from_millis(17))
+        .last(1, Duration::from_millis(22))
+        .last(1, Duration::from_millis(33));
+    let weights: Vec<f32> = lines.iter().map(|line| cursor_steps(line) as f32).collect();
+
+    run(
+        MyState {
+            lines,
+
+            sequencer: Sequencer::weighted(&pacing, &weights),
+            line_index: 0,
+        },
+        options,
+    );
+}
+
+/// The cursor goes through each char, then a bit past the end of the line.
+fn cursor_steps(line: &Line) -> usize {
+    line.chars.len() + 2
+}
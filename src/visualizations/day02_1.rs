@@ -0,0 +1,429 @@
+use std::f32::consts::PI;
+use std::time::Duration;
+
+use crate::helpers::clock::Clock;
+use crate::helpers::easing::{ease_out_bounce, ease_out_cubic, linear};
+use crate::helpers::layout::Layout;
+use crate::helpers::pacing::{Pacing, Sequencer};
+use crate::helpers::renderer::Renderer;
+use crate::helpers::scene::Node;
+use crate::helpers::tween::{Animated, Rotation, Sequence};
+use crate::helpers::{run, seeded_rng, Glow, RunOptions, State, TextManager, TextType};
+use crate::solutions::day02::{Color, Day02, Set, BAG};
+use crate::solutions::Solution;
+use rand::rngs::StdRng;
+use rand::Rng;
+use speedy2d::color::Color as SpeedyColor;
+use speedy2d::dimen::Vector2;
+
+const END_OF_CUBE_OUT: f32 = 0.3;
+const FAST_LINES: usize = 5;
+const TOSS_HEIGHT: f32 = 80.0;
+
+#[derive(Clone, Copy, Debug)]
+struct PositionAndRotation {
+    position: Vector2<f32>,
+    rotation: f32,
+}
+
+/// A cube going out of the bag, to its column.
+#[derive(Clone, Debug)]
+struct MovingCube {
+    position: Animated<Vector2<f32>>,
+    rotation: Animated<Rotation>,
+}
+
+#[derive(Clone)]
+struct MyState {
+    rng: StdRng,
+    games: Vec<Vec<Set>>,
+    score: usize,
+
+    /// One item per game, made of the sets it shows.
+    sequencer: Sequencer,
+    current_set_index: usize,
+    current_game_index: usize,
+    /// Red, green and blue cubes of the current set, taken out of the bag on
+    /// its first frame.
+    current_set_cubes: Option<(Vec<MovingCube>, Vec<MovingCube>, Vec<MovingCube>)>,
+
+    goal_line: f32,
+
+    column_r: f32,
+    column_g: f32,
+    column_b: f32,
+
+    goal_red_cubes: Vec<PositionAndRotation>,
+    goal_green_cubes: Vec<PositionAndRotation>,
+    goal_blue_cubes: Vec<PositionAndRotation>,
+}
+
+impl State for MyState {
+    fn on_draw(
+        &mut self,
+        clock: &Clock,
+        text_manager: &mut TextManager,
+        renderer: &mut dyn Renderer,
+    ) {
+        let layout = renderer.layout();
+
+        let (game_index, set_index, percentage_of_set, set_start, set_duration) =
+            match self.sequencer.at(clock.elapsed()) {
+                Some(step) => {
+                    let sets = self.played_sets(step.index);
+                    let set_progress = step.progress * sets as f32;
+                    let set_index = (set_progress as usize).min(sets - 1);
+                    let set_duration = step.duration / sets as u32;
+
+                    (
+                        step.index,
+                        set_index,
+                        set_progress.fract(),
+                        step.start + set_duration * set_index as u32,
+                        set_duration,
+                    )
+                }
+                None => (self.games.len(), 0, 0.0, Duration::ZERO, Duration::ZERO),
+            };
+        // Several sets can be played between two frames.
+        while (self.current_game_index, self.current_set_index) < (game_index, set_index) {
+            self.next_set();
+        }
+
+        let game_no = self.current_game_index + 1;
+
+        if self.current_game_index == self.games.len() {
+            text_manager.draw_answer(
+                renderer,
+                256,
+                (layout.width() / 2.0, layout.height() / 2.0),
+                self.score.to_string(),
+            );
+
+            return;
+        }
+
+        text_manager.draw_text(
+            renderer,
+            128,
+            TextType::Glow(Glow::WHITE),
+            (layout.width() / 4.0, layout.height() * 0.1),
+            self.score.to_string(),
+        );
+
+        text_manager.draw_text(
+            renderer,
+            80,
+            TextType::Gray,
+            (layout.width() / 2.0, layout.height() - 100.0),
+            format!(
+                "Game n°{game_no} — Set {}/{}",
+                self.current_set_index + 1,
+                self.games[self.current_game_index].len()
+            ),
+        );
+
+        self.draw_goals(
+            renderer,
+            text_manager,
+            self.column_r,
+            &self.goal_red_cubes,
+            Color::Red,
+        );
+        self.draw_goals(
+            renderer,
+            text_manager,
+            self.column_g,
+            &self.goal_green_cubes,
+            Color::Green,
+        );
+        self.draw_goals(
+            renderer,
+            text_manager,
+            self.column_b,
+            &self.goal_blue_cubes,
+            Color::Blue,
+        );
+
+        if self.current_set_cubes.is_none() {
+            self.take_cubes_out(layout, set_start, set_duration.mul_f32(END_OF_CUBE_OUT));
+        }
+        let (red_cubes, green_cubes, blue_cubes) = self.current_set_cubes.as_ref().unwrap();
+        draw_moving_cubes(renderer, text_manager, clock, red_cubes, Color::Red);
+        draw_moving_cubes(renderer, text_manager, clock, green_cubes, Color::Green);
+        draw_moving_cubes(renderer, text_manager, clock, blue_cubes, Color::Blue);
+
+        if percentage_of_set >= END_OF_CUBE_OUT {
+            let value = self.games[self.current_game_index][self.current_set_index].red;
+            text_manager.draw_text(
+                renderer,
+                60,
+                TextType::Glow(if value > BAG.red {
+                    Glow::RED
+                } else {
+                    Glow::GOLD
+                }),
+                (self.column_r, layout.height() * 0.57),
+                value.to_string(),
+            );
+        }
+        if percentage_of_set >= END_OF_CUBE_OUT + (1.0 - END_OF_CUBE_OUT) / 3.0 {
+            let value = self.games[self.current_game_index][self.current_set_index].green;
+            text_manager.draw_text(
+                renderer,
+                60,
+                TextType::Glow(if value > BAG.green {
+                    Glow::RED
+                } else {
+                    Glow::GOLD
+                }),
+                (self.column_g, layout.height() * 0.57),
+                value.to_string(),
+            );
+        }
+        if percentage_of_set
+            >= END_OF_CUBE_OUT + (1.0 - END_OF_CUBE_OUT) / 3.0 + (1.0 - END_OF_CUBE_OUT) / 3.0
+        {
+            let value = self.games[self.current_game_index][self.current_set_index].blue;
+            text_manager.draw_text(
+                renderer,
+                60,
+                TextType::Glow(if value > BAG.blue {
+                    Glow::RED
+                } else {
+                    Glow::GOLD
+                }),
+                (self.column_b, layout.height() * 0.57),
+                value.to_string(),
+            );
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.current_game_index == self.games.len()
+    }
+}
+
+impl MyState {
+    /// Moves to the next set, or to the next game after its last set or a
+    /// set which doesn't fit in the bag.
+    fn next_set(&mut self) {
+        let set = self.games[self.current_game_index][self.current_set_index];
+        if !set.fits_in(&BAG) {
+            // Skip to the next game!
+            self.current_game_index += 1;
+            self.current_set_index = 0;
+        } else {
+            self.current_set_index += 1;
+
+            if self.current_set_index >= self.games[self.current_game_index].len() {
+                self.score += self.current_game_index + 1;
+                self.current_set_index = 0;
+                self.current_game_index += 1;
+            }
+        }
+
+        self.current_set_cubes = None;
+    }
+
+    fn played_sets(&self, game_index: usize) -> usize {
+        played_sets(&self.games[game_index])
+    }
+
+    fn draw_goals(
+        &self,
+        renderer: &mut dyn Renderer,
+        text_manager: &mut TextManager,
+        column: f32,
+        cubes: &Vec<PositionAndRotation>,
+        color: Color,
+    ) {
+        for position_and_rotation in cubes {
+            draw_cube(renderer, text_manager, *position_and_rotation, color, 40.0);
+        }
+        text_manager.draw_text(
+            renderer,
+            80,
+            TextType::Glow(Glow::GOLD),
+            (column, self.goal_line + 110.0),
+            cubes.len().to_string(),
+        );
+    }
+
+    /// The cubes of the current set go from the bag to their column, from
+    /// `start` and during `duration`.
+    fn take_cubes_out(&mut self, layout: Layout, start: Duration, duration: Duration) {
+        let set = self.games[self.current_game_index][self.current_set_index];
+
+        self.current_set_cubes = Some((
+            self.moving_cubes(layout, self.column_r, set.red, start, duration),
+            self.moving_cubes(layout, self.column_g, set.green, start, duration),
+            self.moving_cubes(layout, self.column_b, set.blue, start, duration),
+        ));
+    }
+
+    fn moving_cubes(
+        &mut self,
+        layout: Layout,
+        column: f32,
+        number: u32,
+        start: Duration,
+        duration: Duration,
+    ) -> Vec<MovingCube> {
+        let from = generate_cube_positions(
+            &mut self.rng,
+            Vector2::new(layout.width() / 2.0, layout.height() * 0.79),
+            number,
+        );
+        let to = generate_cube_positions(
+            &mut self.rng,
+            Vector2::new(column, layout.height() / 2.0),
+            number,
+        );
+        // Past the first games, the cubes only spin in their column.
+        let fast = self.current_game_index > FAST_LINES;
+
+        from.into_iter()
+            .zip(to)
+            .map(|(from, to)| {
+                // Thrown above their column, then bouncing into it.
+                let mut position = Animated::new(to.position);
+                if !fast {
+                    let above = to.position - Vector2::new(0.0, TOSS_HEIGHT);
+                    position.play(
+                        start,
+                        Sequence::new(from.position)
+                            .then(above, duration.mul_f32(0.5), ease_out_cubic)
+                            .wait(duration.mul_f32(0.1))
+                            .then(to.position, duration.mul_f32(0.4), ease_out_bounce),
+                    );
+                }
+                let mut rotation = Animated::new(Rotation(from.rotation));
+                rotation.animate(start, Rotation(to.rotation), duration, linear);
+
+                MovingCube { position, rotation }
+            })
+            .collect()
+    }
+}
+
+fn generate_cube_positions(
+    rng: &mut StdRng,
+    position: Vector2<f32>,
+    number: u32,
+) -> Vec<PositionAndRotation> {
+    (0..number)
+        .map(|_| {
+            let random_translation = Vector2::new(
+                (rng.gen::<f32>() - 0.5) * 30.0,
+                (rng.gen::<f32>() - 0.5) * 30.0,
+            );
+            let random_rotation = (rng.gen::<f32>() - 0.5) * 2.0 * PI;
+            let random_position = position + random_translation;
+
+            PositionAndRotation {
+                position: random_position,
+                rotation: random_rotation,
+            }
+        })
+        .collect()
+}
+
+fn draw_moving_cubes(
+    renderer: &mut dyn Renderer,
+    text_manager: &mut TextManager,
+    clock: &Clock,
+    cubes: &[MovingCube],
+    color: Color,
+) {
+    for cube in cubes {
+        let position_and_rotation = PositionAndRotation {
+            position: cube.position.value(clock),
+            rotation: cube.rotation.value(clock).0,
+        };
+
+        draw_cube(renderer, text_manager, position_and_rotation, color, 30.0);
+    }
+}
+
+fn draw_cube(
+    renderer: &mut dyn Renderer,
+    text_manager: &mut TextManager,
+    position_and_rotation: PositionAndRotation,
+    color: Color,
+    size: f32,
+) {
+    let PositionAndRotation { position, rotation } = position_and_rotation;
+    let (border, face) = color.hex_rgb();
+
+    // Here size is the "radius" of the cube, its border going a bit further.
+    Node::group(vec![
+        Node::rect(
+            Vector2::new(size, size) * 2.2,
+            SpeedyColor::from_hex_rgb(border),
+        ),
+        Node::rect(
+            Vector2::new(size, size) * 2.0,
+            SpeedyColor::from_hex_rgb(face),
+        ),
+    ])
+    .at(position)
+    .rotated(rotation)
+    .draw(renderer, text_manager);
+}
+
+pub(crate) fn visualize(input: &str, options: RunOptions) {
+    let mut rng = seeded_rng();
+
+    let games = Day02::parse(input);
+    let pacing = Pacing::new(Duration::from_millis(10))
+        .first(3, Duration::from_millis(2000))
+        .first(FAST_LINES - 2, Duration::from_millis(800));
+    let weights: Vec<f32> = games.iter().map(|sets| played_sets(sets) as f32).collect();
+
+    let layout = options.layout;
+    let goal_line = layout.height() * 0.28;
+
+    let column_r = (layout.width() / 4.0) * 1.0;
+    let column_g = (layout.width() / 4.0) * 2.0;
+    let column_b = (layout.width() / 4.0) * 3.0;
+
+    let goal_red_cubes =
+        generate_cube_positions(&mut rng, Vector2::new(column_r, goal_line), BAG.red);
+    let goal_green_cubes =
+        generate_cube_positions(&mut rng, Vector2::new(column_g, goal_line), BAG.green);
+    let goal_blue_cubes =
+        generate_cube_positions(&mut rng, Vector2::new(column_b, goal_line), BAG.blue);
+
+    run(
+        MyState {
+            rng,
+            games,
+            score: 0,
+
+            sequencer: Sequencer::weighted(&pacing, &weights),
+            current_game_index: 0,
+            current_set_index: 0,
+            current_set_cubes: None,
+
+            goal_line,
+
+            column_r,
+            column_g,
+            column_b,
+
+            goal_red_cubes,
+            goal_green_cubes,
+            goal_blue_cubes,
+        },
+        options,
+    );
+}
+
+/// The sets shown for a game: they stop at the first one which doesn't fit
+/// in the bag.
+fn played_sets(sets: &[Set]) -> usize {
+    sets.iter()
+        .position(|set| !set.fits_in(&BAG))
+        .map_or(sets.len(), |index| index + 1)
+}
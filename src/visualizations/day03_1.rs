@@ -0,0 +1,196 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use speedy2d::dimen::Vector2;
+
+use crate::helpers::camera::Camera;
+use crate::helpers::clock::Clock;
+use crate::helpers::renderer::Renderer;
+use crate::helpers::{run, Glow, RunOptions, State, TextManager, TextType};
+use crate::solutions::day03::{Day03, Number, Schematic, Spot};
+use crate::solutions::Solution;
+
+use crate::helpers::easing::{ease_out_cubic, ease_out_quad};
+
+const MARGIN_Y: f32 = 120.0;
+const LINE_HEIGHT_ADJUSTEMENT: f32 = 0.7;
+const START_NUMBER_OF_LINES: i32 = 10;
+const INTRO_DURATION: Duration = Duration::from_millis(2000);
+const ZOOM_DURATION: Duration = Duration::from_millis(15 * 1000);
+
+#[derive(Clone)]
+struct MyState {
+    schematic: Schematic,
+    /// Positions of the numbers touching a symbol.
+    has_symbol: HashSet<(i32, i32)>,
+
+    /// The schematic is laid out at the size of its first lines, the camera
+    /// zooms out from them.
+    camera: Camera,
+    char_height: f32,
+
+    finished: bool,
+}
+
+impl State for MyState {
+    fn on_draw(
+        &mut self,
+        clock: &Clock,
+        text_manager: &mut TextManager,
+        renderer: &mut dyn Renderer,
+    ) {
+        let layout = renderer.layout();
+
+        let mut score = 0;
+        if self.camera.is_settled(clock) {
+            self.finished = true;
+        }
+
+        let char_height = self.char_height;
+        let char_width = text_manager.measure("0", char_height as u32).advance_width;
+        // The columns are laid out on both sides of the middle of the schematic.
+        let middle_column_index = self.schematic.last_column_index as f32 / 2.0;
+        let line_height = char_height * LINE_HEIGHT_ADJUSTEMENT;
+
+        // The lines above the bottom margin, and the columns on the canvas.
+        let bottom = self
+            .camera
+            .to_world(clock, Vector2::new(0.0, layout.height() - MARGIN_Y))
+            .y;
+        let last_line_index = i32::min(
+            (bottom / line_height).round() as i32 - 1,
+            self.schematic.last_line_index,
+        );
+        let visible = self.camera.visible_rect(clock, layout);
+        let first_column_index = i32::max(
+            (visible.top_left().x / char_width + middle_column_index).ceil() as i32,
+            0,
+        );
+        let last_column_index = i32::min(
+            (visible.bottom_right().x / char_width + middle_column_index).floor() as i32,
+            self.schematic.last_column_index,
+        );
+
+        let mut camera_renderer = self.camera.renderer(clock, renderer);
+        for line_index in 0..=last_line_index {
+            let position_y = (line_index + 1) as f32 * line_height;
+
+            for column_index in first_column_index..=last_column_index {
+                match self.schematic.map.get(&(column_index, line_index)) {
+                    None => {}
+                    Some(Spot::Symbol(string)) => {
+                        text_manager.draw_text(
+                            &mut camera_renderer,
+                            char_height as u32,
+                            TextType::Gray,
+                            (
+                                (column_index as f32 - middle_column_index) * char_width,
+                                position_y,
+                            ),
+                            string.to_string(),
+                        );
+                    }
+                    Some(Spot::Number(Number { string, value })) => {
+                        let has_symbol = self.has_symbol.contains(&(column_index, line_index));
+                        if column_index + (string.len() as i32) - 1 <= last_column_index {
+                            for (index, char) in string.chars().enumerate() {
+                                text_manager.draw_text(
+                                    &mut camera_renderer,
+                                    char_height as u32,
+                                    if has_symbol {
+                                        TextType::Glow(Glow::GOLD)
+                                    } else {
+                                        TextType::Glow(Glow::RED)
+                                    },
+                                    (
+                                        ((column_index + index as i32) as f32
+                                            - middle_column_index)
+                                            * char_width,
+                                        position_y,
+                                    ),
+                                    char.to_string(),
+                                );
+                            }
+
+                            if has_symbol {
+                                score += value;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let score_position = (layout.width() / 3.0, layout.height() * 0.1);
+        if self.finished {
+            text_manager.draw_answer(renderer, 128, score_position, score.to_string());
+        } else {
+            text_manager.draw_text(
+                renderer,
+                128,
+                TextType::Glow(Glow::WHITE),
+                score_position,
+                score.to_string(),
+            );
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+pub(crate) fn visualize(input: &str, options: RunOptions) {
+    let schematic = Day03::parse(input);
+
+    let layout = options.layout;
+    let available_height = layout.height() * 0.8 - MARGIN_Y;
+    let char_height =
+        (available_height / (START_NUMBER_OF_LINES as f32 * LINE_HEIGHT_ADJUSTEMENT)).floor();
+    let zoom = |number_of_lines: i32| {
+        available_height / (number_of_lines as f32 * char_height * LINE_HEIGHT_ADJUSTEMENT)
+    };
+
+    // The schematic comes up from the bottom of the canvas, then its top
+    // stays in place while the lines under it come in.
+    let mut camera = Camera::new(layout)
+        .anchored(
+            Vector2::new(
+                layout.width() / 2.0,
+                layout.height() - MARGIN_Y - available_height,
+            ),
+            Vector2::new(0.0, -available_height / zoom(START_NUMBER_OF_LINES)),
+        )
+        .zoomed(zoom(START_NUMBER_OF_LINES));
+    camera.pan_to(
+        Duration::ZERO,
+        Vector2::ZERO,
+        INTRO_DURATION,
+        ease_out_cubic,
+    );
+    camera.zoom_to(
+        Duration::ZERO,
+        zoom(i32::max(
+            schematic.last_line_index + 1,
+            START_NUMBER_OF_LINES,
+        )),
+        ZOOM_DURATION,
+        ease_out_quad,
+    );
+    let has_symbol = schematic
+        .numbers()
+        .filter(|(position, number)| schematic.has_symbol(*position, number))
+        .map(|(position, _)| position)
+        .collect();
+
+    run(
+        MyState {
+            schematic,
+            has_symbol,
+            camera,
+            char_height,
+            finished: false,
+        },
+        options,
+    );
+}
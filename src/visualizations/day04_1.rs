@@ -0,0 +1,456 @@
+use std::{collections::HashSet, f32::consts::PI, time::Duration};
+
+use crate::helpers::{run, seeded_rng, Glow, RunOptions, State, TextManager, TextType};
+use image::{ImageBuffer, Rgba};
+use rand::Rng as _;
+use speedy2d::dimen::Vector2;
+use speedy2d::shape::Rectangle;
+
+use crate::helpers::clock::Clock;
+use crate::helpers::easing::ease_out_cubic;
+use crate::helpers::layout::Layout;
+use crate::helpers::pacing::{Pacing, Sequencer};
+use crate::helpers::renderer::{Renderer, Texture};
+use crate::helpers::scene::{Affine, Node};
+use crate::helpers::text::{Align, TextLayout, VerticalAlign};
+use crate::helpers::tween::Animated;
+use crate::solutions::day04::Day04;
+use crate::solutions::Solution;
+
+/// The digits of a card fill its lines one after the other.
+const NUMBER_OF_COLUMNS: usize = 5;
+
+/// Width over height of the card.
+const CARD_RATIO: f32 = 648.0 / 576.0;
+const CARD_OUTER_BORDER: f32 = 30.0;
+const CARD_INNER_BORDER: f32 = 30.0;
+const CARD_FULL_BORDER: f32 = CARD_OUTER_BORDER + CARD_INNER_BORDER;
+
+const COLUMNS_MARGINS: f32 = (NUMBER_OF_COLUMNS as f32 - 1.0) * CARD_INNER_BORDER;
+
+/// How many scratch overlays a card goes through while being scratched.
+const SCRATCH_LEVELS: f32 = 60.0;
+
+const BORDER_COLOR: image::Rgba<u8> = image::Rgba([7, 89, 133, 255]);
+const BACKGROUND_COLOR: image::Rgba<u8> = image::Rgba([15, 15, 35, 255]);
+const FOREGROUND_COLOR: image::Rgba<u8> = image::Rgba([203, 213, 225, 255]);
+
+/// The card sits on the stage of the layout, the texts go in its header.
+#[derive(Clone)]
+struct CardLayout {
+    header: Rectangle,
+    width: f32,
+    height: f32,
+    center: Vector2<f32>,
+    random_translation_bounds: f32,
+    column_width: f32,
+}
+
+impl CardLayout {
+    fn new(layout: Layout) -> Self {
+        let (header, stage) = layout.split(0.5);
+
+        let width = f32::min(stage.width() * 0.8, stage.height() * 0.8 * CARD_RATIO);
+        let height = width / CARD_RATIO;
+
+        let inner_width = width - 2.0 * CARD_FULL_BORDER;
+        let column_width = (inner_width - COLUMNS_MARGINS) / NUMBER_OF_COLUMNS as f32;
+
+        CardLayout {
+            header,
+            width,
+            height,
+            center: Vector2::new(
+                stage.top_left().x + stage.width() / 2.0,
+                stage.bottom_right().y - 100.0 - height / 2.0,
+            ),
+            random_translation_bounds: (stage.width() - width) / 2.0,
+            column_width,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct MyState {
+    card_layout: CardLayout,
+    empty_card: Option<Texture>,
+    /// The card on top, under its digits.
+    card_background: Option<Texture>,
+    /// The scratch overlay by its scratch level, created again only when the
+    /// scratching moves on.
+    scratch_image: Option<(u32, Texture)>,
+    cards: Vec<Card>,
+    sequencer: Sequencer,
+    current_card_index: usize,
+}
+
+impl State for MyState {
+    fn on_start(&mut self, renderer: &mut dyn Renderer) {
+        let card_layout = self.card_layout.clone();
+        let empty_card = create_empty_card_image(&card_layout, FOREGROUND_COLOR);
+
+        let image_handle = renderer.create_image_from_raw_pixels(
+            (card_layout.width as u32, card_layout.height as u32),
+            &empty_card,
+        );
+
+        self.empty_card = Some(image_handle);
+
+        let card_background = create_empty_card_image(&card_layout, BACKGROUND_COLOR);
+        self.card_background = Some(renderer.create_image_from_raw_pixels(
+            (card_layout.width as u32, card_layout.height as u32),
+            &card_background,
+        ));
+    }
+
+    fn on_draw(
+        &mut self,
+        clock: &Clock,
+        text_manager: &mut TextManager,
+        renderer: &mut dyn Renderer,
+    ) {
+        let layout = renderer.layout();
+        let card_layout = self.card_layout.clone();
+        let header = &card_layout.header;
+        let message_position = (
+            header.top_left().x + header.width() / 2.0,
+            header.top_left().y + header.height() * 0.72,
+        );
+
+        // The card is scratched during this part of its step, and leaves
+        // from the start of the last one.
+        let scratch_end = 0.7;
+        let leave_start = 0.9;
+
+        let step = self.sequencer.at(clock.elapsed());
+        self.current_card_index = step.map_or(self.cards.len(), |step| step.index);
+        let current_card_index = self.current_card_index;
+
+        let score: u32 = self.cards[0..current_card_index]
+            .iter()
+            .map(|card| card.score)
+            .sum();
+
+        let Some(step) = step else {
+            text_manager.draw_answer(
+                renderer,
+                128 * 2,
+                (layout.width() / 2.0, layout.height() / 2.0),
+                score.to_string(),
+            );
+
+            return;
+        };
+
+        // The card on top straightens up before being scratched.
+        let current_card = &mut self.cards[current_card_index];
+        if *current_card.offset.target() != Vector2::ZERO {
+            let duration = step.duration.mul_f32(0.15);
+            current_card
+                .offset
+                .animate(step.start, Vector2::ZERO, duration, ease_out_cubic);
+            current_card
+                .rotation
+                .animate(step.start, 0.0, duration, ease_out_cubic);
+        }
+
+        // The pile under the card on top.
+        for offset in (1..15).rev() {
+            let card_index = current_card_index + offset;
+            if card_index >= self.cards.len() {
+                continue;
+            }
+
+            let card = &self.cards[card_index];
+            Node::image(self.empty_card.clone().unwrap())
+                .at(card_layout.center + card.offset.value(clock))
+                .rotated(card.rotation.value(clock))
+                .draw(renderer, text_manager);
+        }
+
+        let current_card = &self.cards[current_card_index];
+
+        let scratch_level =
+            ((step.progress / scratch_end).clamp(0.0, 1.0) * SCRATCH_LEVELS).round() as u32;
+        if !matches!(self.scratch_image, Some((level, _)) if level == scratch_level) {
+            let image =
+                create_to_scratch_image(&card_layout, scratch_level as f32 / SCRATCH_LEVELS);
+            let texture = renderer.create_image_from_raw_pixels(image.dimensions(), &image);
+            self.scratch_image = Some((scratch_level, texture));
+        }
+
+        // The digits go between the card and its scratch overlay, which is
+        // smaller than the card, both are centered.
+        let mut children = vec![Node::image(self.card_background.clone().unwrap())];
+        children.extend(card_digits(text_manager, &card_layout, current_card));
+        children.push(Node::image(self.scratch_image.as_ref().unwrap().1.clone()));
+
+        // Then it grows and fades away, showing the next one.
+        let leaving = ((step.progress - leave_start) / (1.0 - leave_start)).clamp(0.0, 1.0);
+        Node::group(children)
+            .at(card_layout.center + current_card.offset.value(clock))
+            .rotated(current_card.rotation.value(clock))
+            .scaled(1.0 + 0.15 * leaving)
+            .with_opacity(1.0 - leaving)
+            .draw(renderer, text_manager);
+
+        if step.progress > scratch_end {
+            if current_card.number_of_winning_digits == 0 {
+                // On the same bottom line as the score of the other cards.
+                let metrics = text_manager.measure("", 100);
+                text_manager.draw_text_layout(
+                    renderer,
+                    60,
+                    TextType::Glow(Glow::WHITE),
+                    (
+                        message_position.0,
+                        message_position.1 + (metrics.ascent + metrics.descent) / 2.0,
+                    ),
+                    "Aucun numéro.\nAucun point.".to_string(),
+                    TextLayout::new(Align::Center, VerticalAlign::Bottom).with_line_spacing(1.3),
+                );
+            } else {
+                draw_score(
+                    text_manager,
+                    renderer,
+                    message_position,
+                    current_card.number_of_winning_digits,
+                    current_card.score,
+                );
+            }
+        }
+
+        text_manager.draw_text(
+            renderer,
+            128,
+            TextType::Glow(Glow::GOLD),
+            (
+                header.top_left().x + header.width() / 4.0,
+                header.top_left().y + 150.0,
+            ),
+            score.to_string(),
+        );
+    }
+
+    fn is_finished(&self) -> bool {
+        self.current_card_index >= self.cards.len()
+    }
+}
+
+pub(crate) fn visualize(input: &str, options: RunOptions) {
+    let mut rng = seeded_rng();
+    let card_layout = CardLayout::new(options.layout);
+
+    let cards = Day04::parse(input)
+        .into_iter()
+        .map(|scratchcard| {
+            let offset = Vector2::new(
+                (rng.gen::<f32>() - 0.5) * 2.0 * card_layout.random_translation_bounds,
+                (rng.gen::<f32>() - 0.5) * 2.0 * card_layout.random_translation_bounds,
+            );
+            let rotation = (rng.gen::<f32>() - 0.5) * 2.0 * (PI / 20.0);
+
+            Card {
+                number_of_winning_digits: scratchcard.number_of_winning_digits(),
+                score: scratchcard.score(),
+                digits: scratchcard.digits,
+                winning_digits: scratchcard.winning_digits,
+                offset: Animated::new(offset),
+                rotation: Animated::new(rotation),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let pacing = Pacing::new(Duration::from_millis(50))
+        .first(3, Duration::from_millis(3000))
+        .first(3, Duration::from_millis(1000));
+
+    run(
+        MyState {
+            card_layout,
+            empty_card: None,
+            card_background: None,
+            scratch_image: None,
+            sequencer: Sequencer::new(&pacing, cards.len()),
+            cards,
+            current_card_index: 0,
+        },
+        options,
+    );
+}
+
+#[derive(Clone)]
+struct Card {
+    digits: Vec<u32>,
+    winning_digits: HashSet<u32>,
+    number_of_winning_digits: u32,
+    score: u32,
+    /// From the center of the stage, cards are randomly scattered.
+    offset: Animated<Vector2<f32>>,
+    rotation: Animated<f32>,
+}
+
+/// `2^(n-1)=score`, centered on `position`, with the exponent raised on its
+/// own baseline and its number of winning digits in gold.
+fn draw_score(
+    text_manager: &mut TextManager,
+    renderer: &mut dyn Renderer,
+    position: (f32, f32),
+    number_of_winning_digits: u32,
+    score: u32,
+) {
+    let metrics = text_manager.measure("", 100);
+    let baseline = position.1 + (metrics.ascent - metrics.descent) / 2.0;
+    let exponent_baseline = baseline - metrics.ascent * 0.45;
+    let pieces = [
+        ("2".to_string(), 100, Glow::WHITE, baseline),
+        (
+            number_of_winning_digits.to_string(),
+            60,
+            Glow::GOLD,
+            exponent_baseline,
+        ),
+        ("-1".to_string(), 60, Glow::WHITE, exponent_baseline),
+        (format!("={score}"), 100, Glow::WHITE, baseline),
+    ];
+
+    let width: f32 = pieces
+        .iter()
+        .map(|(text, size, ..)| text_manager.measure(text, *size).advance_width)
+        .sum();
+    let mut x = position.0 - width / 2.0;
+    for (text, size, glow, baseline) in pieces {
+        let advance_width = text_manager.measure(&text, size).advance_width;
+        text_manager.draw_text_layout(
+            renderer,
+            size,
+            TextType::Glow(glow),
+            (x, baseline),
+            text,
+            TextLayout::new(Align::Left, VerticalAlign::Baseline),
+        );
+        x += advance_width;
+    }
+}
+
+fn create_empty_card_image(
+    card_layout: &CardLayout,
+    fill_color: image::Rgba<u8>,
+) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
+    let (width, height) = (card_layout.width, card_layout.height);
+
+    ImageBuffer::from_fn(width as u32, height as u32, |x, y| {
+        if (x as f32) < CARD_OUTER_BORDER
+            || (x as f32) > width - CARD_OUTER_BORDER
+            || (y as f32) < CARD_OUTER_BORDER
+            || (y as f32) > height - CARD_OUTER_BORDER
+        {
+            BORDER_COLOR
+        } else {
+            fill_color
+        }
+    })
+}
+
+/// The digits of `card`, from its center, the winning ones in gold.
+fn card_digits(text_manager: &TextManager, card_layout: &CardLayout, card: &Card) -> Vec<Node> {
+    let &CardLayout {
+        width,
+        height,
+        column_width,
+        ..
+    } = card_layout;
+
+    // Two digits fill a column.
+    let font_size =
+        (column_width / 2.0 / text_manager.measure("0", 100).advance_width * 100.0) as u32;
+    let metrics = text_manager.measure("", font_size);
+    let number_of_lines = card.digits.len().div_ceil(NUMBER_OF_COLUMNS);
+    let lines_height = number_of_lines as f32 * font_size as f32
+        + (number_of_lines as f32 - 1.0) * CARD_INNER_BORDER;
+    // Text nodes are centered on their position, between ascent and descent.
+    let first_line_y = (height - lines_height) / 2.0 + (metrics.ascent + metrics.descent) / 2.0;
+
+    card.digits
+        .iter()
+        .enumerate()
+        .map(|(digit_index, &digit)| {
+            let column_index = digit_index % NUMBER_OF_COLUMNS;
+            let line_index = digit_index / NUMBER_OF_COLUMNS;
+
+            Node::text(
+                digit.to_string(),
+                font_size,
+                if card.winning_digits.contains(&digit) {
+                    TextType::Glow(Glow::GOLD)
+                } else {
+                    TextType::Glow(Glow::WHITE)
+                },
+            )
+            .at(Vector2::new(
+                CARD_FULL_BORDER
+                    + column_index as f32 * (column_width + CARD_INNER_BORDER)
+                    + column_width / 2.0
+                    - width / 2.0,
+                first_line_y + line_index as f32 * (font_size as f32 + CARD_INNER_BORDER)
+                    - height / 2.0,
+            ))
+        })
+        .collect()
+}
+
+fn create_to_scratch_image(
+    card_layout: &CardLayout,
+    timing: f32,
+) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
+    let inner_width = card_layout.width - 2.0 * CARD_OUTER_BORDER;
+    let inner_height = card_layout.height - 2.0 * CARD_OUTER_BORDER;
+
+    let scratches = [
+        (
+            Vector2::new(inner_width * 0.35, inner_height * 0.20),
+            -(PI / 20.0),
+            70.0,
+        ),
+        (
+            Vector2::new(inner_width * 0.35, inner_height * 0.40),
+            PI / 40.0,
+            65.0,
+        ),
+        (
+            Vector2::new(inner_width * 0.5, inner_height * 0.85),
+            PI / 35.0,
+            45.0,
+        ),
+        (
+            Vector2::new(inner_width * 0.55, inner_height * 0.65),
+            -(PI / 30.0),
+            65.0,
+        ),
+    ];
+
+    let scratch_duration: f32 = 1.0 / scratches.len() as f32;
+
+    let scratch_index = (timing / scratch_duration).floor() as usize;
+    let this_scratch_timing =
+        (timing - (scratch_index as f32 * scratch_duration)) / scratch_duration;
+    let scratch_width = inner_width * 1.5;
+    let this_scratch_width = inner_width * 1.5 * this_scratch_timing;
+
+    ImageBuffer::from_fn(inner_width as u32, inner_height as u32, |x, y| {
+        for (index, (center, rotation, height)) in scratches.iter().enumerate() {
+            let point =
+                Affine::rotation(-rotation).apply(Vector2::new(x as f32, y as f32) - center);
+
+            if index <= scratch_index
+                && point.y > -height
+                && point.y < *height
+                && (index < scratch_index || point.x + scratch_width / 2.0 < this_scratch_width)
+            {
+                return Rgba([0, 0, 0, 0]);
+            }
+        }
+
+        FOREGROUND_COLOR
+    })
+}
@@ -0,0 +1,349 @@
+use std::time::Duration;
+
+use crate::helpers::{rect_to_array, run, Glow, RunOptions, State, TextManager, TextType};
+use image::{ImageBuffer, Rgba};
+use speedy2d::color::Color;
+use speedy2d::dimen::Vector2;
+use speedy2d::shape::Rectangle;
+
+use crate::helpers::camera::Camera;
+use crate::helpers::clock::Clock;
+use crate::helpers::easing::ease_in_out_cubic;
+use crate::helpers::layout::Layout;
+use crate::helpers::pacing::{Pacing, Sequencer};
+use crate::helpers::renderer::{Renderer, Texture};
+use crate::solutions::day04::Day04;
+use crate::solutions::Solution;
+
+const NUMBER_OF_COLUMNS: usize = 5;
+const NUMBER_OF_LINES: usize = 8;
+
+const MARGIN: f32 = 30.0;
+
+const CARD_OUTER_BORDER: f32 = 10.0;
+
+const BORDER_COLOR: image::Rgba<u8> = image::Rgba([7, 89, 133, 255]);
+const BACKGROUND_COLOR: image::Rgba<u8> = image::Rgba([15, 15, 35, 255]);
+// const FOREGROUND_COLOR: image::Rgba<u8> = image::Rgba([203, 213, 225, 255]);
+
+/// The grid of cards fills the stage of the layout, the texts go in its header.
+/// The grid goes on below the stage for the other cards, see `camera_focus`.
+struct GridLayout {
+    header: Rectangle,
+    stage: Rectangle,
+    card_width: f32,
+    card_height: f32,
+}
+
+impl GridLayout {
+    fn new(layout: Layout) -> Self {
+        let (header, stage) = layout.split(0.4);
+
+        GridLayout {
+            card_width: (stage.width() - 2.0 * MARGIN - (NUMBER_OF_COLUMNS - 1) as f32 * MARGIN)
+                / NUMBER_OF_COLUMNS as f32,
+            card_height: (stage.height() - 2.0 * MARGIN - (NUMBER_OF_LINES - 1) as f32 * MARGIN)
+                / NUMBER_OF_LINES as f32,
+            header,
+            stage,
+        }
+    }
+
+    fn card_top_left(&self, index: usize) -> Vector2<f32> {
+        let line_index = index / NUMBER_OF_COLUMNS;
+        let column_index = index % NUMBER_OF_COLUMNS;
+
+        *self.stage.top_left()
+            + Vector2::new(
+                column_index as f32 * (self.card_width + MARGIN) + MARGIN,
+                line_index as f32 * (self.card_height + MARGIN) + MARGIN,
+            )
+    }
+
+    fn card_center(&self, index: usize) -> (f32, f32) {
+        let top_left = self.card_top_left(index);
+
+        (
+            top_left.x + self.card_width / 2.0,
+            top_left.y + self.card_height / 2.0,
+        )
+    }
+
+    /// The camera looks at the line above the current card, so the copies
+    /// it gives can be seen, until the last lines are on the stage.
+    fn camera_focus(
+        &self,
+        layout: Layout,
+        current_card_index: usize,
+        number_of_cards: usize,
+    ) -> Vector2<f32> {
+        let number_of_lines = number_of_cards.div_ceil(NUMBER_OF_COLUMNS);
+        let top_line_index = (current_card_index / NUMBER_OF_COLUMNS)
+            .saturating_sub(1)
+            .min(number_of_lines.saturating_sub(NUMBER_OF_LINES));
+
+        Vector2::new(
+            layout.width() / 2.0,
+            layout.height() / 2.0 + top_line_index as f32 * (self.card_height + MARGIN),
+        )
+    }
+
+    /// `ratio` of the way down the header, centered.
+    fn header_position(&self, ratio: f32) -> (f32, f32) {
+        (
+            self.header.top_left().x + self.header.width() / 2.0,
+            self.header.top_left().y + self.header.height() * ratio,
+        )
+    }
+
+    /// Shrinks the header texts when the header is narrower than the
+    /// portrait canvas they were sized for.
+    fn header_font_size(&self, size: u32) -> u32 {
+        (size as f32 * (self.header.width() / Layout::PORTRAIT.width()).min(1.0)) as u32
+    }
+}
+
+#[derive(Clone)]
+struct MyState {
+    empty_card: Option<Texture>,
+    cards: Vec<Card>,
+    sequencer: Sequencer,
+    current_card_index: usize,
+    camera: Camera,
+}
+
+impl State for MyState {
+    fn on_start(&mut self, renderer: &mut dyn Renderer) {
+        let grid_layout = GridLayout::new(renderer.layout());
+        let empty_card = create_empty_card_image(&grid_layout, BACKGROUND_COLOR);
+
+        let image_handle = renderer.create_image_from_raw_pixels(
+            (
+                grid_layout.card_width as u32,
+                grid_layout.card_height as u32,
+            ),
+            &empty_card,
+        );
+
+        self.empty_card = Some(image_handle);
+    }
+
+    fn on_draw(
+        &mut self,
+        clock: &Clock,
+        text_manager: &mut TextManager,
+        renderer: &mut dyn Renderer,
+    ) {
+        let layout = renderer.layout();
+        let grid_layout = GridLayout::new(layout);
+
+        let step = self.sequencer.at(clock.elapsed());
+        let step_index = step.map_or(self.cards.len(), |step| step.index);
+
+        // The cards played since the last frame still give all their copies.
+        while self.current_card_index < step_index {
+            let last_update_todo =
+                self.cards[self.current_card_index].number_of_winning_digits as usize;
+            update_cards(&mut self.cards, self.current_card_index, last_update_todo);
+            self.current_card_index += 1;
+        }
+        let current_card_index = self.current_card_index;
+
+        let score: u64 = self.cards[0..current_card_index]
+            .iter()
+            .map(|card| card.quantity)
+            .sum();
+
+        let Some(step) = step else {
+            text_manager.draw_answer(
+                renderer,
+                128,
+                (layout.width() / 2.0, layout.height() / 2.0),
+                score.to_string(),
+            );
+
+            return;
+        };
+
+        self.camera.follow(
+            step.start,
+            grid_layout.camera_focus(layout, current_card_index, self.cards.len()),
+        );
+        let visible = self.camera.visible_rect(clock, layout);
+        let mut camera_renderer = self.camera.renderer(clock, renderer);
+
+        for (index, card) in self.cards.iter().enumerate() {
+            let top_left = grid_layout.card_top_left(index);
+            if top_left.y + grid_layout.card_height < visible.top_left().y
+                || top_left.y > visible.bottom_right().y
+            {
+                continue;
+            }
+
+            camera_renderer.draw_image(top_left, self.empty_card.as_ref().unwrap());
+
+            text_manager.draw_text(
+                &mut camera_renderer,
+                45,
+                if index == current_card_index {
+                    TextType::Glow(Glow::GOLD)
+                } else {
+                    TextType::Glow(Glow::WHITE)
+                },
+                grid_layout.card_center(index),
+                card.quantity.to_string(),
+            )
+        }
+
+        let number_of_winning_digits = self.cards[current_card_index].number_of_winning_digits;
+        let current_quantity = self.cards[current_card_index].quantity;
+        if number_of_winning_digits > 0 {
+            // The copies are given one after the other, between two margins.
+            let margin = 0.2;
+            let updates_progress = (step.progress - margin) / (1.0 - margin * 2.0);
+            let update_progress = updates_progress * number_of_winning_digits as f32;
+
+            if updates_progress > 0.0 {
+                let last_update_todo = (update_progress.floor() as usize + 1)
+                    .clamp(0, number_of_winning_digits as usize);
+
+                update_cards(&mut self.cards, current_card_index, last_update_todo);
+
+                for offset in 1..=last_update_todo {
+                    if current_card_index + offset < self.cards.len() {
+                        let mut position = grid_layout.card_center(current_card_index + offset);
+
+                        position.1 -= if offset == last_update_todo {
+                            if updates_progress > 1.0 {
+                                50.0
+                            } else {
+                                50.0 - 10.0 * (1.0 - update_progress.fract())
+                            }
+                        } else {
+                            50.0
+                        };
+
+                        text_manager.draw_text(
+                            &mut camera_renderer,
+                            80,
+                            TextType::Glow(Glow::GOLD),
+                            position,
+                            format!("+{}", current_quantity),
+                        );
+                    }
+                }
+            }
+        }
+
+        // The cards going up are hidden behind the header.
+        renderer.draw_quad(
+            rect_to_array(&grid_layout.header),
+            Color::from_hex_rgb(0x0f0f23),
+        );
+
+        text_manager.draw_text(
+            renderer,
+            grid_layout.header_font_size(80),
+            TextType::Glow(Glow::WHITE),
+            grid_layout.header_position(0.38),
+            score.to_string(),
+        );
+
+        text_manager.draw_text(
+            renderer,
+            grid_layout.header_font_size(70),
+            TextType::Glow(Glow::WHITE),
+            grid_layout.header_position(0.73),
+            format!("Carte n°{}/{}", current_card_index + 1, self.cards.len()),
+        );
+        text_manager.draw_text(
+            renderer,
+            grid_layout.header_font_size(80),
+            TextType::Glow(Glow::WHITE),
+            grid_layout.header_position(0.9),
+            format!("{} numéros gagnants", number_of_winning_digits),
+        );
+    }
+
+    fn is_finished(&self) -> bool {
+        self.current_card_index >= self.cards.len()
+    }
+}
+
+pub(crate) fn visualize(input: &str, options: RunOptions) {
+    let cards = Day04::parse(input)
+        .into_iter()
+        .map(|scratchcard| Card {
+            number_of_winning_digits: scratchcard.number_of_winning_digits(),
+            quantity: 1,
+            last_update_done: 0,
+        })
+        .collect::<Vec<_>>();
+
+    let pacing = Pacing::new(Duration::from_millis(50))
+        .first(3, Duration::from_millis(3000))
+        .first(3, Duration::from_millis(1000));
+
+    run(
+        MyState {
+            empty_card: None,
+            sequencer: Sequencer::new(&pacing, cards.len()),
+            cards,
+            current_card_index: 0,
+            camera: Camera::new(options.layout)
+                .following(Duration::from_millis(400), ease_in_out_cubic),
+        },
+        options,
+    );
+}
+
+#[derive(Debug, Clone)]
+struct Card {
+    number_of_winning_digits: u32,
+    quantity: u64,
+    last_update_done: usize,
+}
+
+fn create_empty_card_image(
+    grid_layout: &GridLayout,
+    fill_color: image::Rgba<u8>,
+) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
+    let (width, height) = (grid_layout.card_width, grid_layout.card_height);
+
+    ImageBuffer::from_fn(width as u32, height as u32, |x, y| {
+        if (x as f32) < CARD_OUTER_BORDER
+            || (x as f32) > width - CARD_OUTER_BORDER
+            || (y as f32) < CARD_OUTER_BORDER
+            || (y as f32) > height - CARD_OUTER_BORDER
+        {
+            BORDER_COLOR
+        } else {
+            fill_color
+        }
+    })
+}
+
+fn update_cards(cards: &mut [Card], current_card_index: usize, last_update_todo: usize) {
+    let last_update_done = cards[current_card_index].last_update_done;
+    let current_quantity = cards[current_card_index].quantity;
+
+    if last_update_done > last_update_todo {
+        unreachable!(
+            "copies are only given forward, but card {current_card_index} gave to its next {last_update_done} cards and now to {last_update_todo}"
+        );
+    }
+    if last_update_done == last_update_todo {
+        return;
+    }
+
+    for offset in last_update_done + 1..=last_update_todo {
+        let index = current_card_index + offset;
+        if index >= cards.len() {
+            break;
+        }
+
+        cards[index].quantity += current_quantity;
+    }
+
+    cards[current_card_index].last_update_done = last_update_todo;
+}
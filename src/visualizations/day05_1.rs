@@ -0,0 +1,224 @@
+use std::{collections::HashMap, time::Duration};
+
+use crate::helpers::{
+    array_to_rectangle, run, square_at_position, Glow, RunOptions, State, TextManager, TextType,
+};
+use speedy2d::dimen::Vector2;
+
+use crate::helpers::clock::Clock;
+use crate::helpers::pacing::{Pacing, Sequencer};
+use crate::helpers::renderer::{Renderer, Texture};
+use crate::helpers::text::Align;
+use crate::solutions::day05::{category_color, part_index_to_string, Day05, Section};
+use crate::solutions::Solution;
+
+#[derive(Clone)]
+struct MyState {
+    sequencer: Sequencer,
+    current_seed_index: usize,
+    seeds: Vec<Seed>,
+    maps: HashMap<String, Section>,
+
+    images: Vec<Texture>,
+}
+
+impl State for MyState {
+    fn on_start(&mut self, renderer: &mut dyn Renderer) {
+        for i in 0..8 {
+            let name = part_index_to_string(i);
+            self.images
+                .push(renderer.create_image_from_file_path(&format!("data/{name}.png")))
+        }
+    }
+    fn on_draw(
+        &mut self,
+        clock: &Clock,
+        text_manager: &mut TextManager,
+        renderer: &mut dyn Renderer,
+    ) {
+        let layout = renderer.layout();
+
+        let step = self.sequencer.at(clock.elapsed());
+        self.current_seed_index = step.map_or(self.seeds.len(), |step| step.index);
+        let current_seed_index = self.current_seed_index;
+
+        let Some(step) = step else {
+            let min_location = self
+                .seeds
+                .iter()
+                .map(|seed| seed.last().unwrap())
+                .min()
+                .unwrap();
+            let height = layout.height() / (self.seeds.len() as f32 * 1.1 + 2.0);
+            for (index, seed) in self.seeds.iter().enumerate() {
+                let is_answer = seed.last().unwrap() == min_location;
+                let color = if is_answer {
+                    text_manager.answer_type(&min_location.to_string())
+                } else {
+                    TextType::Glow(Glow::WHITE)
+                };
+                let line_y = layout.height() / 2.0
+                    + (index as f32 - (self.seeds.len() as f32) / 2.0) * height;
+
+                renderer.draw_rectangle_image(
+                    array_to_rectangle(square_at_position(Vector2::new(50.0, line_y), 20.0)),
+                    &self.images[0],
+                );
+
+                text_manager.draw_text_align(
+                    renderer,
+                    40,
+                    color,
+                    (75.0, line_y),
+                    seed[0].to_string(),
+                    Align::Left,
+                );
+
+                renderer.draw_rectangle_image(
+                    array_to_rectangle(square_at_position(
+                        Vector2::new(layout.width() - 50.0, line_y),
+                        20.0,
+                    )),
+                    self.images.last().unwrap(),
+                );
+
+                if is_answer {
+                    text_manager.draw_answer_align(
+                        renderer,
+                        40,
+                        (layout.width() - 75.0, line_y),
+                        min_location.to_string(),
+                        Align::Right,
+                    );
+                } else {
+                    text_manager.draw_text_align(
+                        renderer,
+                        40,
+                        color,
+                        (layout.width() - 75.0, line_y),
+                        seed.last().unwrap().to_string(),
+                        Align::Right,
+                    );
+                }
+            }
+
+            return;
+        };
+
+        // Each seed goes through the 8 categories.
+        let part_index = (step.progress * 8.0).floor() as usize + 1;
+
+        for index in 0..part_index {
+            if index >= 8 {
+                break;
+            }
+            let value = self.seeds[current_seed_index][index];
+
+            renderer.draw_rectangle_image(
+                array_to_rectangle(square_at_position(
+                    Vector2::new(50.0, 50.0 + index as f32 * 50.0),
+                    20.0,
+                )),
+                &self.images[index],
+            );
+
+            text_manager.draw_text_align(
+                renderer,
+                40,
+                TextType::Glow(Glow::from_hex_rgb(category_color(index))),
+                (75.0, 50.0 + index as f32 * 50.0),
+                value.to_string(),
+                Align::Left,
+            );
+        }
+
+        if part_index < 8 {
+            let map = &self.maps.get(part_index_to_string(part_index - 1)).unwrap();
+            let value = self.seeds[current_seed_index][part_index - 1];
+
+            text_manager.draw_text(
+                renderer,
+                40,
+                TextType::Gray,
+                (layout.width() / 2.0, layout.height() / 2.0 - 70.0),
+                format!("from {} to {}", map.from, map.to),
+            );
+            let height = (layout.height() / 2.0) / (map.ranges.len() as f32 * 1.1 + 2.0);
+
+            for (i, range) in map.ranges.iter().enumerate() {
+                let line_y = layout.height() / 2.0 + i as f32 * height;
+
+                let color =
+                    if value >= range.source_start && value < range.source_start + range.length {
+                        TextType::Glow(Glow::GOLD)
+                    } else {
+                        TextType::Glow(Glow::WHITE)
+                    };
+
+                text_manager.draw_text_align(
+                    renderer,
+                    25,
+                    color,
+                    (80.0, line_y),
+                    range.source_start.to_string(),
+                    Align::Left,
+                );
+
+                if value >= range.source_start && value < range.source_start + range.length {
+                    let diff = range.destination_start - range.source_start;
+                    let diff_text = if diff > 0 {
+                        format!("+{diff}")
+                    } else {
+                        diff.to_string()
+                    };
+                    text_manager.draw_text(
+                        renderer,
+                        25,
+                        color,
+                        (layout.width() / 2.0, line_y),
+                        diff_text,
+                    );
+                }
+                text_manager.draw_text_align(
+                    renderer,
+                    25,
+                    color,
+                    (layout.width() - 80.0, line_y),
+                    (range.source_start + range.length).to_string(),
+                    Align::Right,
+                );
+            }
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.current_seed_index >= self.seeds.len()
+    }
+}
+
+/// The value of the seed in each category.
+type Seed = Vec<i64>;
+
+pub(crate) fn visualize(input: &str, options: RunOptions) {
+    let almanac = Day05::parse(input);
+    let seeds = almanac
+        .seeds
+        .iter()
+        .map(|seed| almanac.seed_path(*seed))
+        .collect::<Vec<_>>();
+
+    let pacing = Pacing::new(Duration::from_millis(50))
+        .first(3, Duration::from_millis(8000))
+        .first(3, Duration::from_millis(1000));
+
+    run(
+        MyState {
+            sequencer: Sequencer::new(&pacing, seeds.len()),
+            seeds,
+            maps: almanac.maps,
+            current_seed_index: 0,
+            images: vec![],
+        },
+        options,
+    );
+}
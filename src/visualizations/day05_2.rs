@@ -0,0 +1,134 @@
+use std::{cmp::min, ops::Range, time::Duration};
+
+use crate::helpers::{
+    array_to_rectangle, run, square_at_position, Glow, RunOptions, State, TextManager, TextType,
+};
+use speedy2d::dimen::Vector2;
+
+use crate::helpers::clock::Clock;
+use crate::helpers::pacing::{Pacing, Sequencer};
+use crate::helpers::renderer::{Renderer, Texture};
+use crate::helpers::text::Align;
+use crate::solutions::day05::{category_color, part_index_to_string, Day05};
+use crate::solutions::Solution;
+
+#[derive(Clone)]
+struct MyState {
+    values: Vec<Vec<Range<i64>>>,
+    sequencer: Sequencer,
+    current_phase: usize,
+
+    images: Vec<Texture>,
+}
+
+impl State for MyState {
+    fn on_start(&mut self, renderer: &mut dyn Renderer) {
+        for i in 0..8 {
+            let name = part_index_to_string(i);
+            self.images
+                .push(renderer.create_image_from_file_path(&format!("data/{name}.png")))
+        }
+    }
+    fn on_draw(
+        &mut self,
+        clock: &Clock,
+        text_manager: &mut TextManager,
+        renderer: &mut dyn Renderer,
+    ) {
+        let layout = renderer.layout();
+
+        self.current_phase = self
+            .sequencer
+            .at(clock.elapsed())
+            .map_or(self.values.len(), |step| step.index);
+
+        if self.current_phase >= self.values.len() {
+            let score = self
+                .values
+                .last()
+                .unwrap()
+                .iter()
+                .map(|range| range.start)
+                .min()
+                .unwrap();
+
+            text_manager.draw_answer(
+                renderer,
+                128,
+                (layout.width() / 2.0, layout.height() / 2.0),
+                score.to_string(),
+            );
+
+            return;
+        }
+
+        renderer.draw_rectangle_image(
+            array_to_rectangle(square_at_position(Vector2::new(200.0, 200.0), 80.0)),
+            &self.images[self.current_phase],
+        );
+
+        const START_LINE: f32 = 200.0 + 80.0 + (200.0 - 80.0);
+        let height_available = layout.height() - START_LINE;
+        let line_y_center = START_LINE + height_available / 2.0;
+
+        let values = &self.values[self.current_phase];
+        let value_height = height_available / (values.len() as f32 + 2.0);
+
+        for (i, value) in values.iter().enumerate() {
+            let line_y = line_y_center + (i as f32 - values.len() as f32 / 2.0) * value_height;
+            let font_size = min(25, (value_height * 0.8) as u32);
+
+            // Small and close together, the lines glow less far.
+            let color = TextType::Glow(
+                Glow::from_hex_rgb(category_color(self.current_phase)).with_radius(4),
+            );
+
+            text_manager.draw_text_align(
+                renderer,
+                font_size,
+                color,
+                (layout.width() / 2.0 - 40.0, line_y),
+                value.start.to_string(),
+                Align::Right,
+            );
+            text_manager.draw_text(
+                renderer,
+                font_size,
+                color,
+                (layout.width() / 2.0, line_y),
+                "<->".to_string(),
+            );
+            text_manager.draw_text_align(
+                renderer,
+                font_size,
+                color,
+                (layout.width() / 2.0 + 40.0, line_y),
+                value.end.to_string(),
+                Align::Left,
+            );
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.current_phase >= self.values.len()
+    }
+}
+
+pub(crate) fn visualize(input: &str, options: RunOptions) {
+    let almanac = Day05::parse(input);
+    let values = almanac.ranges_path(almanac.seed_ranges());
+
+    let pacing = Pacing::new(Duration::from_millis(1000))
+        .first(1, Duration::from_millis(5000))
+        .first(1, Duration::from_millis(3000));
+
+    run(
+        MyState {
+            sequencer: Sequencer::new(&pacing, values.len()),
+            values,
+            current_phase: 0,
+            images: vec![],
+        },
+        options,
+    );
+}
@@ -0,0 +1,39 @@
+use crate::helpers::clock::Clock;
+use crate::helpers::renderer::Renderer;
+use crate::helpers::{run, RunOptions, State, TextManager};
+use crate::solutions::day06::Day06;
+use crate::solutions::Solution;
+
+#[derive(Clone)]
+struct MyState {
+    score: u64,
+}
+
+impl State for MyState {
+    fn on_draw(
+        &mut self,
+        _clock: &Clock,
+        text_manager: &mut TextManager,
+        renderer: &mut dyn Renderer,
+    ) {
+        let layout = renderer.layout();
+
+        text_manager.draw_answer(
+            renderer,
+            256,
+            (layout.width() / 2.0, layout.height() / 2.0),
+            self.score.to_string(),
+        );
+    }
+
+    fn is_finished(&self) -> bool {
+        true
+    }
+}
+
+pub(crate) fn visualize(input: &str, options: RunOptions) {
+    let races = Day06::parse(input);
+    let score = Day06::part2(&races);
+
+    run(MyState { score }, options);
+}